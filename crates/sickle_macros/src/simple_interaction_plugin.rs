@@ -7,7 +7,7 @@ use syn::{Ident, Result, Token};
 #[derive(Debug)]
 pub struct SimpleInteractionPluginParams {
     pub controlled_component: syn::Ident,
-    pub target_type: syn::Ident,
+    pub target_type: syn::Type,
     pub target_prop: Option<syn::LitStr>,
 }
 
@@ -48,27 +48,47 @@ pub(crate) fn impl_simple_interaction_plugin_macro(attr: TokenStream, name: Iden
 
         controller_belly = quote! {
             fn extract_value(from: &Self::ControlledComponent) -> Self::TargetType {
-                from.#target_prop_id
+                from.#target_prop_id.clone()
             }
 
             fn update_controlled_component(
                 mut controlled_component: Mut<'_, Self::ControlledComponent>,
                 new_value: Self::TargetType,
             ) {
-                controlled_component.#target_prop_id = new_value;
+                if controlled_component.#target_prop_id.reflect_partial_eq(&new_value) != Some(true) {
+                    controlled_component.#target_prop_id = new_value;
+                }
+            }
+        };
+    } else if is_same_type(&component, &target_type) {
+        // The whole component is the controlled value (i.e. enums like `Visibility`)
+        controller_belly = quote! {
+            fn extract_value(from: &Self::ControlledComponent) -> Self::TargetType {
+                from.clone()
+            }
+
+            fn update_controlled_component(
+                mut controlled_component: Mut<'_, Self::ControlledComponent>,
+                new_value: Self::TargetType,
+            ) {
+                if (*controlled_component).reflect_partial_eq(&new_value) != Some(true) {
+                    *controlled_component = new_value;
+                }
             }
         };
     } else {
         controller_belly = quote! {
             fn extract_value(from: &Self::ControlledComponent) -> Self::TargetType {
-                from.0
+                from.0.clone()
             }
 
             fn update_controlled_component(
                 mut controlled_component: Mut<'_, Self::ControlledComponent>,
                 new_value: Self::TargetType,
             ) {
-                controlled_component.0 = new_value;
+                if controlled_component.0.reflect_partial_eq(&new_value) != Some(true) {
+                    controlled_component.0 = new_value;
+                }
             }
         };
     }
@@ -77,11 +97,18 @@ pub(crate) fn impl_simple_interaction_plugin_macro(attr: TokenStream, name: Iden
     let state_ident = Ident::new(state_name.as_str(), Span::call_site());
 
     let gen = quote! {
-        #[derive(Component, Debug, Default, Reflect)]
+        #[derive(Component, Clone, Debug, Default, Reflect)]
         pub struct #name {
             pub highlight: Option<#target_type>,
             pub pressed: Option<#target_type>,
             pub cancel: Option<#target_type>,
+            pub focused: Option<#target_type>,
+            pub selected: Option<#target_type>,
+            pub checked: Option<#target_type>,
+            pub active: Option<#target_type>,
+            pub disabled: Option<#target_type>,
+            pub idle: Option<#target_type>,
+            pub delays: StateDelays,
         }
 
         impl InteractionConfig for #name {
@@ -93,20 +120,65 @@ pub(crate) fn impl_simple_interaction_plugin_macro(attr: TokenStream, name: Iden
                 cancel: Option<Self::TargetType>,
             )-> Self{
                 Self{
-                    highlight, pressed, cancel
+                    highlight,
+                    pressed,
+                    cancel,
+                    ..Default::default()
                 }
             }
 
             fn highlight(&self) -> Option<Self::TargetType> {
-                self.highlight
+                self.highlight.clone()
             }
 
             fn pressed(&self) -> Option<Self::TargetType> {
-                self.pressed
+                self.pressed.clone()
             }
 
             fn cancel(&self) -> Option<Self::TargetType> {
-                self.cancel
+                self.cancel.clone()
+            }
+
+            fn state_value(&self, state: StyleState) -> Option<Self::TargetType> {
+                match state {
+                    StyleState::Default => None,
+                    StyleState::Checked => self.checked.clone(),
+                    StyleState::Selected => self.selected.clone(),
+                    StyleState::Active => self.active.clone(),
+                    StyleState::Hover => self.highlight.clone(),
+                    StyleState::Focused => self.focused.clone(),
+                    StyleState::Pressed => self.pressed.clone(),
+                    StyleState::Disabled => self.disabled.clone(),
+                }
+            }
+
+            fn set_state_value(&mut self, state: StyleState, value: Option<Self::TargetType>) {
+                match state {
+                    StyleState::Default => (),
+                    StyleState::Checked => self.checked = value,
+                    StyleState::Selected => self.selected = value,
+                    StyleState::Active => self.active = value,
+                    StyleState::Hover => self.highlight = value,
+                    StyleState::Focused => self.focused = value,
+                    StyleState::Pressed => self.pressed = value,
+                    StyleState::Disabled => self.disabled = value,
+                }
+            }
+
+            fn idle(&self) -> Option<Self::TargetType> {
+                self.idle.clone()
+            }
+
+            fn set_idle(&mut self, value: Option<Self::TargetType>) {
+                self.idle = value;
+            }
+
+            fn delays(&self) -> StateDelays {
+                self.delays
+            }
+
+            fn set_delays(&mut self, delays: StateDelays) {
+                self.delays = delays;
             }
         }
 
@@ -114,16 +186,24 @@ pub(crate) fn impl_simple_interaction_plugin_macro(attr: TokenStream, name: Iden
         pub struct #state_ident {
             original: #target_type,
             transition_base: #target_type,
+            style_state: StyleState,
+            pending_style_state: Option<PendingStyleState>,
         }
 
         impl InteractionState for #state_ident {
             type TargetType = #target_type;
 
             fn original(&self) -> Self::TargetType {
-                self.original
+                self.original.clone()
             }
             fn transition_base(&self) -> Self::TargetType {
-                self.transition_base
+                self.transition_base.clone()
+            }
+            fn style_state(&self) -> StyleState {
+                self.style_state
+            }
+            fn pending_style_state(&self) -> Option<PendingStyleState> {
+                self.pending_style_state
             }
             fn set_original(&mut self, from: Self::TargetType) {
                 self.original = from;
@@ -131,6 +211,12 @@ pub(crate) fn impl_simple_interaction_plugin_macro(attr: TokenStream, name: Iden
             fn set_transition_base(&mut self, from: Self::TargetType) {
                 self.transition_base = from;
             }
+            fn set_style_state(&mut self, state: StyleState) {
+                self.style_state = state;
+            }
+            fn set_pending_style_state(&mut self, pending: Option<PendingStyleState>) {
+                self.pending_style_state = pending;
+            }
         }
 
         impl ComponentController for #name {
@@ -142,6 +228,8 @@ pub(crate) fn impl_simple_interaction_plugin_macro(attr: TokenStream, name: Iden
                 Self::InteractionState {
                     original: Self::extract_value(from),
                     transition_base: Self::extract_value(from),
+                    style_state: StyleState::Default,
+                    pending_style_state: None,
                 }
             }
 
@@ -168,21 +256,12 @@ pub(crate) fn impl_simple_interaction_plugin_macro(attr: TokenStream, name: Iden
                 )
                 .add_systems(
                     Update,
-                    (
-                        update_transition_base_state::<
-                            #name,
-                            #state_ident,
-                            #component,
-                            #target_type,
-                        >,
-                        update_controlled_component::<
-                            #name,
-                            #state_ident,
-                            #component,
-                            #target_type,
-                        >,
-                    )
-                        .chain()
+                    update_controlled_component::<
+                        #name,
+                        #state_ident,
+                        #component,
+                        #target_type,
+                    >
                         .after(AnimatedInteractionUpdate),
                 );
             }
@@ -190,3 +269,11 @@ pub(crate) fn impl_simple_interaction_plugin_macro(attr: TokenStream, name: Iden
     };
     gen.into()
 }
+
+fn is_same_type(component: &Ident, target_type: &syn::Type) -> bool {
+    let syn::Type::Path(type_path) = target_type else {
+        return false;
+    };
+
+    type_path.qself.is_none() && type_path.path.is_ident(component)
+}
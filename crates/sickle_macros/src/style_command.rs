@@ -54,6 +54,63 @@ fn parse_lock_attribute(
     }
 }
 
+/// Commands marked `#[animatable]` transition to the new value if the entity has a
+/// `StyleTransitions` entry for the locked attribute.
+fn parse_transition(
+    ast: &syn::DeriveInput,
+    target_attr: proc_macro2::Ident,
+    check_lock: bool,
+) -> Result<proc_macro2::TokenStream, proc_macro2::TokenStream> {
+    let Some(animatable) = ast
+        .attrs
+        .iter()
+        .find(|attr| attr.style == AttrStyle::Outer && attr.path().is_ident("animatable"))
+    else {
+        return Ok(proc_macro2::TokenStream::new());
+    };
+
+    let error = quote_spanned! {
+        animatable.path().get_ident().unwrap().span() => compile_error!( "Unsupported animatable value. Must be defined as #[animatable] next to #[lock_attr(StylableAttribute::VARIANT)]");
+    };
+
+    let Meta::Path(_) = &animatable.meta else {
+        return Err(error.into());
+    };
+
+    let Some(lock_attr) = ast
+        .attrs
+        .iter()
+        .find(|attr| attr.style == AttrStyle::Outer && attr.path().is_ident("lock_attr"))
+    else {
+        return Err(error.into());
+    };
+
+    let Meta::List(list) = &lock_attr.meta else {
+        return Err(error.into());
+    };
+
+    let Some(TokenTree::Ident(variant)) = list.tokens.clone().into_iter().last() else {
+        return Err(error.into());
+    };
+
+    let stylable_attr = list.tokens.clone();
+    Ok(quote! {
+        if let Some(config) = world
+            .get::<StyleTransitions>(entity)
+            .and_then(|transitions| transitions.get(#stylable_attr))
+        {
+            transition_style(
+                entity,
+                world,
+                AnimatedStyleValue::#variant(self.#target_attr),
+                config,
+                #check_lock,
+            );
+            return;
+        }
+    })
+}
+
 fn parse_target_setter(
     ast: &syn::DeriveInput,
     target_attr: proc_macro2::Ident,
@@ -196,6 +253,16 @@ pub(crate) fn derive_style_command_macro(ast: &syn::DeriveInput) -> TokenStream
         Err(error) => return error.into(),
     };
 
+    let transition = match parse_transition(ast, target_attr.clone(), true) {
+        Ok(stream) => stream,
+        Err(error) => return error.into(),
+    };
+
+    let transition_unchecked = match parse_transition(ast, target_attr.clone(), false) {
+        Ok(stream) => stream,
+        Err(error) => return error.into(),
+    };
+
     let value_setter = match parse_target_setter(
         ast,
         target_attr.clone(),
@@ -210,6 +277,7 @@ pub(crate) fn derive_style_command_macro(ast: &syn::DeriveInput) -> TokenStream
         impl EntityCommand for #name_ident {
             fn apply(self, entity: Entity, world: &mut World) {
                 #stylable_attr_check
+                #transition
                 #value_setter
             }
         }
@@ -233,6 +301,7 @@ pub(crate) fn derive_style_command_macro(ast: &syn::DeriveInput) -> TokenStream
 
         impl EntityCommand for #name_unchecked_ident {
             fn apply(self, entity: Entity, world: &mut World) {
+                #transition_unchecked
                 #value_setter
             }
         }
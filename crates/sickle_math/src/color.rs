@@ -0,0 +1,165 @@
+use bevy::{reflect::Reflect, render::color::Color};
+use serde::{Deserialize, Serialize};
+
+use crate::lerp::Lerp;
+
+/// The color space colors are interpolated in.
+///
+/// Raw sRGB channels produce dull midpoints between saturated hues, Oklab keeps them
+/// perceptually even and is the default. HSL / HSV take the shortest way around the hue circle.
+#[derive(Default, Copy, Clone, Debug, Hash, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub enum ColorSpace {
+    Srgb,
+    LinearRgb,
+    Hsl,
+    Hsv,
+    #[default]
+    Oklab,
+}
+
+/// Interpolates between two colors in `color_space`. Alpha is always interpolated linearly.
+pub fn lerp_color(from: Color, to: Color, t: f32, color_space: ColorSpace) -> Color {
+    let alpha = from.a().lerp(to.a(), t).clamp(0., 1.);
+
+    match color_space {
+        ColorSpace::Srgb => Color::rgba(
+            from.r().lerp(to.r(), t).clamp(0., 1.),
+            from.g().lerp(to.g(), t).clamp(0., 1.),
+            from.b().lerp(to.b(), t).clamp(0., 1.),
+            alpha,
+        ),
+        ColorSpace::LinearRgb => {
+            let [from_r, from_g, from_b, _] = from.as_linear_rgba_f32();
+            let [to_r, to_g, to_b, _] = to.as_linear_rgba_f32();
+
+            Color::rgba_linear(
+                from_r.lerp(to_r, t).max(0.),
+                from_g.lerp(to_g, t).max(0.),
+                from_b.lerp(to_b, t).max(0.),
+                alpha,
+            )
+            .as_rgba()
+        }
+        ColorSpace::Hsl => {
+            let [from_h, from_s, from_l, _] = from.as_hsla_f32();
+            let [to_h, to_s, to_l, _] = to.as_hsla_f32();
+            let (from_h, to_h) = hue_endpoints(from_h, from_s, to_h, to_s);
+
+            Color::hsla(
+                lerp_hue(from_h, to_h, t),
+                from_s.lerp(to_s, t).clamp(0., 1.),
+                from_l.lerp(to_l, t).clamp(0., 1.),
+                alpha,
+            )
+            .as_rgba()
+        }
+        ColorSpace::Hsv => {
+            let [from_h, from_s, from_v] = srgb_to_hsv(from);
+            let [to_h, to_s, to_v] = srgb_to_hsv(to);
+            let (from_h, to_h) = hue_endpoints(from_h, from_s, to_h, to_s);
+
+            hsv_to_srgb(
+                lerp_hue(from_h, to_h, t),
+                from_s.lerp(to_s, t).clamp(0., 1.),
+                from_v.lerp(to_v, t).clamp(0., 1.),
+                alpha,
+            )
+        }
+        ColorSpace::Oklab => {
+            let [from_l, from_a, from_b] = linear_srgb_to_oklab(from);
+            let [to_l, to_a, to_b] = linear_srgb_to_oklab(to);
+
+            oklab_to_srgb(
+                from_l.lerp(to_l, t),
+                from_a.lerp(to_a, t),
+                from_b.lerp(to_b, t),
+                alpha,
+            )
+        }
+    }
+}
+
+/// Grays have no meaningful hue, they take the hue of the other color
+fn hue_endpoints(from_h: f32, from_s: f32, to_h: f32, to_s: f32) -> (f32, f32) {
+    match (from_s == 0., to_s == 0.) {
+        (true, false) => (to_h, to_h),
+        (false, true) => (from_h, from_h),
+        _ => (from_h, to_h),
+    }
+}
+
+fn lerp_hue(from: f32, to: f32, t: f32) -> f32 {
+    let difference = (to - from + 540.).rem_euclid(360.) - 180.;
+    (from + difference * t).rem_euclid(360.)
+}
+
+fn srgb_to_hsv(color: Color) -> [f32; 3] {
+    let [r, g, b, _] = color.as_rgba_f32();
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let hue = if delta == 0. {
+        0.
+    } else if max == r {
+        60. * ((g - b) / delta).rem_euclid(6.)
+    } else if max == g {
+        60. * ((b - r) / delta + 2.)
+    } else {
+        60. * ((r - g) / delta + 4.)
+    };
+    let saturation = if max == 0. { 0. } else { delta / max };
+
+    [hue, saturation, max]
+}
+
+fn hsv_to_srgb(hue: f32, saturation: f32, value: f32, alpha: f32) -> Color {
+    let chroma = value * saturation;
+    let sector = hue / 60.;
+    let x = chroma * (1. - (sector.rem_euclid(2.) - 1.).abs());
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.),
+        1 => (x, chroma, 0.),
+        2 => (0., chroma, x),
+        3 => (0., x, chroma),
+        4 => (x, 0., chroma),
+        _ => (chroma, 0., x),
+    };
+    let m = value - chroma;
+
+    Color::rgba(r + m, g + m, b + m, alpha)
+}
+
+fn linear_srgb_to_oklab(color: Color) -> [f32; 3] {
+    let [r, g, b, _] = color.as_linear_rgba_f32();
+    let l = (0.41222147 * r + 0.53633254 * g + 0.05144599 * b).cbrt();
+    let m = (0.2119035 * r + 0.6806995 * g + 0.10739696 * b).cbrt();
+    let s = (0.08830246 * r + 0.28171884 * g + 0.6299787 * b).cbrt();
+
+    [
+        0.21045426 * l + 0.7936178 * m - 0.00407205 * s,
+        1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+        0.02590404 * l + 0.78277177 * m - 0.80867577 * s,
+    ]
+}
+
+fn oklab_to_srgb(lightness: f32, a: f32, b: f32, alpha: f32) -> Color {
+    let l = (lightness + 0.39633778 * a + 0.21580376 * b).powi(3);
+    let m = (lightness - 0.10556135 * a - 0.06385417 * b).powi(3);
+    let s = (lightness - 0.08948418 * a - 1.2914855 * b).powi(3);
+
+    let color = Color::rgba_linear(
+        (4.0767417 * l - 3.3077116 * m + 0.23096993 * s).max(0.),
+        (-1.268438 * l + 2.6097574 * m - 0.3413194 * s).max(0.),
+        (-0.00419609 * l - 0.7034186 * m + 1.7076147 * s).max(0.),
+        alpha,
+    )
+    .as_rgba();
+
+    Color::rgba(
+        color.r().clamp(0., 1.),
+        color.g().clamp(0., 1.),
+        color.b().clamp(0., 1.),
+        alpha,
+    )
+}
@@ -1,6 +1,7 @@
-use std::f32::consts::PI;
+use std::{f32::consts::PI, sync::RwLock};
 
-use bevy::reflect::Reflect;
+use bevy::reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 const C1_F32: f32 = 1.70158;
 const C2_F32: f32 = C1_F32 * 1.525;
@@ -19,7 +20,7 @@ const D1_F32: f32 = 2.75;
 // const N1_F64: f64 = 7.5625;
 // const D1_F64: f64 = 2.75;
 
-#[derive(Default, Copy, Clone, Debug, Hash, PartialEq, Eq, Reflect)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
 pub enum Ease {
     #[default]
     Linear,
@@ -53,6 +54,168 @@ pub enum Ease {
     InBounce,
     OutBounce,
     InOutBounce,
+    /// CSS `cubic-bezier(x1, y1, x2, y2)`. The x coordinates are clamped to `0..=1`.
+    CubicBezier(f32, f32, f32, f32),
+    /// CSS `steps(n, jump)`
+    Steps(u32, StepJump),
+    /// A curve registered with [`CustomEase::register`]
+    Custom(CustomEase),
+}
+
+/// Where the jumps of [`Ease::Steps`] happen, same as the CSS step positions.
+#[derive(Default, Copy, Clone, Debug, Hash, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub enum StepJump {
+    Start,
+    #[default]
+    End,
+    None,
+    Both,
+}
+
+/// Name and curve, if registered already
+type CustomEaseEntry = (String, Option<fn(f32) -> f32>);
+
+static CUSTOM_EASES: RwLock<Vec<CustomEaseEntry>> = RwLock::new(Vec::new());
+
+/// A named, user provided easing curve.
+///
+/// (De)serializes as its name, so themes can refer to curves registered by the app.
+/// Unregistered curves ease linearly.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, Reflect)]
+#[reflect_value(Debug, Hash, PartialEq, Serialize, Deserialize)]
+pub struct CustomEase(usize);
+
+impl CustomEase {
+    /// Registers `curve` under `name`, replacing any curve registered with the same name.
+    pub fn register(name: impl Into<String>, curve: fn(f32) -> f32) -> Self {
+        let ease = CustomEase::named(name);
+        if let Ok(mut custom_eases) = CUSTOM_EASES.write() {
+            custom_eases[ease.0].1 = Some(curve);
+        }
+
+        ease
+    }
+
+    /// Looks up the curve with `name`, which may be registered later.
+    pub fn named(name: impl Into<String>) -> Self {
+        let name = name.into();
+        if let Some(index) = CUSTOM_EASES.read().ok().and_then(|custom_eases| {
+            custom_eases
+                .iter()
+                .position(|(custom_name, _)| *custom_name == name)
+        }) {
+            return Self(index);
+        }
+
+        let mut custom_eases = CUSTOM_EASES.write().unwrap_or_else(|err| err.into_inner());
+        // Another thread may have added the name in the meantime
+        if let Some(index) = custom_eases
+            .iter()
+            .position(|(custom_name, _)| *custom_name == name)
+        {
+            return Self(index);
+        }
+
+        custom_eases.push((name, None));
+        Self(custom_eases.len() - 1)
+    }
+
+    pub fn name(&self) -> String {
+        CUSTOM_EASES
+            .read()
+            .ok()
+            .and_then(|custom_eases| custom_eases.get(self.0).map(|(name, _)| name.clone()))
+            .unwrap_or_default()
+    }
+
+    fn sample(&self, x: f32) -> f32 {
+        let curve = CUSTOM_EASES
+            .read()
+            .ok()
+            .and_then(|custom_eases| custom_eases.get(self.0).and_then(|(_, curve)| *curve));
+
+        match curve {
+            Some(curve) => curve(x),
+            None => x,
+        }
+    }
+}
+
+impl Serialize for CustomEase {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name().as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomEase {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(CustomEase::named(name))
+    }
+}
+
+fn cubic_bezier(x: f32, x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
+    let x1 = x1.clamp(0., 1.);
+    let x2 = x2.clamp(0., 1.);
+    let bezier = |t: f32, p1: f32, p2: f32| {
+        let u = 1. - t;
+        3. * u * u * t * p1 + 3. * u * t * t * p2 + t * t * t
+    };
+    let slope = |t: f32, p1: f32, p2: f32| {
+        let u = 1. - t;
+        3. * u * u * p1 + 6. * u * t * (p2 - p1) + 3. * t * t * (1. - p2)
+    };
+
+    // Newton's method converges fast for most curves, bisection covers flat slopes
+    let mut t = x;
+    for _ in 0..8 {
+        let error = bezier(t, x1, x2) - x;
+        if error.abs() < 1e-6 {
+            return bezier(t, y1, y2);
+        }
+
+        let dx = slope(t, x1, x2);
+        if dx.abs() < 1e-6 {
+            break;
+        }
+
+        t = (t - error / dx).clamp(0., 1.);
+    }
+
+    let (mut low, mut high) = (0., 1.);
+    t = x;
+    for _ in 0..32 {
+        let value = bezier(t, x1, x2);
+        if (value - x).abs() < 1e-6 {
+            break;
+        }
+
+        if value < x {
+            low = t;
+        } else {
+            high = t;
+        }
+
+        t = (low + high) / 2.;
+    }
+
+    bezier(t, y1, y2)
+}
+
+fn steps(x: f32, steps: u32, jump: StepJump) -> f32 {
+    let steps = steps.max(1) as f32;
+    let mut step = (x * steps).floor();
+    if jump == StepJump::Start || jump == StepJump::Both {
+        step += 1.;
+    }
+
+    let jumps = match jump {
+        StepJump::Start | StepJump::End => steps,
+        StepJump::None => (steps - 1.).max(1.),
+        StepJump::Both => steps + 1.,
+    };
+
+    (step / jumps).clamp(0., 1.)
 }
 
 pub trait ValueEasing {
@@ -211,6 +374,9 @@ impl ValueEasing for f32 {
                     (1. + (2. * x - 1.).ease(Ease::OutBounce)) / 2.
                 }
             }
+            Ease::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x, x1, y1, x2, y2),
+            Ease::Steps(count, jump) => steps(x, count, jump),
+            Ease::Custom(custom_ease) => custom_ease.sample(x),
         }
     }
 }
@@ -1,10 +1,27 @@
 use bevy::{
-    render::color::Color,
-    ui::{UiRect, Val},
+    asset::Handle,
+    math::{Quat, Vec2, Vec3, Vec4},
+    render::{color::Color, texture::Image, view::Visibility},
+    sprite::ImageScaleMode,
+    ui::{
+        AlignContent, AlignItems, AlignSelf, Direction, Display, FlexDirection, FlexWrap,
+        FocusPolicy, GridAutoFlow, GridPlacement, GridTrack, JustifyContent, JustifyItems,
+        JustifySelf, Overflow, PositionType, RepeatedGridTrack, UiRect, Val, ZIndex,
+    },
 };
 
+use crate::color::{lerp_color, ColorSpace};
+
 pub trait Lerp {
     fn lerp(&self, to: Self, t: f32) -> Self;
+
+    /// Same as [`Lerp::lerp`], interpolating colors in `color_space`
+    fn lerp_in(&self, to: Self, t: f32, _color_space: ColorSpace) -> Self
+    where
+        Self: Sized,
+    {
+        self.lerp(to, t)
+    }
 }
 
 pub trait Lerp64 {
@@ -29,14 +46,37 @@ impl Lerp64 for f64 {
     }
 }
 
+impl Lerp for Vec2 {
+    fn lerp(&self, to: Self, t: f32) -> Self {
+        Vec2::lerp(*self, to, t)
+    }
+}
+
+impl Lerp for Vec3 {
+    fn lerp(&self, to: Self, t: f32) -> Self {
+        Vec3::lerp(*self, to, t)
+    }
+}
+
+impl Lerp for Vec4 {
+    fn lerp(&self, to: Self, t: f32) -> Self {
+        Vec4::lerp(*self, to, t)
+    }
+}
+
+impl Lerp for Quat {
+    fn lerp(&self, to: Self, t: f32) -> Self {
+        self.slerp(to, t)
+    }
+}
+
 impl Lerp for Color {
     fn lerp(&self, to: Self, t: f32) -> Self {
-        Color::rgba(
-            self.r().lerp(to.r(), t).clamp(0., 1.),
-            self.g().lerp(to.g(), t).clamp(0., 1.),
-            self.b().lerp(to.b(), t).clamp(0., 1.),
-            self.a().lerp(to.a(), t).clamp(0., 1.),
-        )
+        lerp_color(*self, to, t, ColorSpace::default())
+    }
+
+    fn lerp_in(&self, to: Self, t: f32, color_space: ColorSpace) -> Self {
+        lerp_color(*self, to, t, color_space)
     }
 }
 
@@ -101,3 +141,57 @@ impl Lerp for UiRect {
         )
     }
 }
+
+impl Lerp for Option<f32> {
+    fn lerp(&self, to: Self, t: f32) -> Self {
+        match (self, to) {
+            (Some(value), Some(other)) => Some(value.lerp(other, t)),
+            _ => discrete_lerp(self, to, t),
+        }
+    }
+}
+
+/// Values that cannot be interpolated flip to the target halfway through the transition.
+fn discrete_lerp<T: Clone>(from: &T, to: T, t: f32) -> T {
+    if t < 0.5 {
+        from.clone()
+    } else {
+        to
+    }
+}
+
+macro_rules! impl_discrete_lerp {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl Lerp for $type {
+                fn lerp(&self, to: Self, t: f32) -> Self {
+                    discrete_lerp(self, to, t)
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete_lerp!(
+    Display,
+    PositionType,
+    Overflow,
+    Direction,
+    AlignItems,
+    JustifyItems,
+    AlignSelf,
+    JustifySelf,
+    AlignContent,
+    JustifyContent,
+    FlexDirection,
+    FlexWrap,
+    GridAutoFlow,
+    GridPlacement,
+    Vec<RepeatedGridTrack>,
+    Vec<GridTrack>,
+    FocusPolicy,
+    Visibility,
+    ZIndex,
+    Handle<Image>,
+    ImageScaleMode,
+);
@@ -1,2 +1,4 @@
+pub mod color;
 pub mod ease;
 pub mod lerp;
+pub mod spring;
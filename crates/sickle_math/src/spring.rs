@@ -0,0 +1,52 @@
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+/// Largest time step used to integrate a spring, larger frames are split up
+const MAX_SPRING_STEP: f32 = 1. / 240.;
+const REST_DISTANCE: f32 = 0.001;
+const REST_VELOCITY: f32 = 0.01;
+
+/// A damped harmonic oscillator pulling a value towards a target.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Spring {
+    pub stiffness: f32,
+    pub damping: f32,
+    pub mass: f32,
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self {
+            stiffness: 170.,
+            damping: 26.,
+            mass: 1.,
+        }
+    }
+}
+
+impl Spring {
+    /// Advances `position` and `velocity` by `delta` seconds, returning the new pair.
+    ///
+    /// Uses semi-implicit Euler integration in fixed sub-steps to stay stable on long frames.
+    pub fn step(&self, position: f32, velocity: f32, target: f32, delta: f32) -> (f32, f32) {
+        let delta = delta.max(0.);
+        let steps = (delta / MAX_SPRING_STEP).ceil().max(1.);
+        let dt = delta / steps;
+        let mass = self.mass.max(f32::EPSILON);
+
+        let mut position = position;
+        let mut velocity = velocity;
+        for _ in 0..steps as u32 {
+            let force = -self.stiffness * (position - target) - self.damping * velocity;
+            velocity += force / mass * dt;
+            position += velocity * dt;
+        }
+
+        (position, velocity)
+    }
+
+    pub fn is_at_rest(&self, position: f32, velocity: f32, target: f32) -> bool {
+        (position - target).abs() < REST_DISTANCE && velocity.abs() < REST_VELOCITY
+    }
+}
@@ -6,6 +6,10 @@ use sickle_ui::{
         hierarchy::{HierarchyTreeViewPlugin, UiHierarchyExt},
         scene_view::{SceneView, SceneViewPlugin, SpawnSceneViewPreUpdate, UiSceneViewExt},
     },
+    theme::{
+        tokens::{ColorScheme, ColorToken, ThemeTokens, TokenStyle},
+        ThemeAsset,
+    },
     ui_builder::{UiBuilderExt, UiContextRoot, UiRoot},
     ui_commands::SetCursorExt,
     ui_style::{SetBackgroundColorExt, SetNodeHeightExt, SetNodeWidthExt},
@@ -35,6 +39,7 @@ fn main() {
         .add_systems(OnEnter(Page::Playground), interaction_showcase)
         .add_systems(OnExit(Page::Playground), clear_content_on_menu_change)
         .add_systems(PreUpdate, exit_app_on_menu_item)
+        .add_systems(PreUpdate, update_color_scheme)
         .add_systems(
             PreUpdate,
             (spawn_hierarchy_view, despawn_hierarchy_view).after(SpawnSceneViewPreUpdate),
@@ -70,6 +75,10 @@ enum Page {
 #[reflect(Component)]
 struct ExitAppButton;
 
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+struct LightSchemeToggle;
+
 #[derive(Component, Debug, Default, Reflect)]
 #[reflect(Component)]
 struct ShowcaseContainer;
@@ -146,6 +155,8 @@ fn setup(
                 ..default()
             },
             TargetCamera(main_camera),
+            // Widget themes, re-applied on change with the `file_watcher` feature
+            asset_server.load::<ThemeAsset>("examples/themes/simple_editor.theme.ron"),
         ),
         |container| {
             root_entity = container
@@ -185,15 +196,18 @@ fn setup(
 
     // Use the UI builder of the root entity with styling applied via commands
     commands.ui_builder(root_entity).column(|column| {
-        column
-            .style()
-            .width(Val::Percent(100.))
-            .background_color(Color::rgb(0.15, 0.155, 0.16));
+        column.style().width(Val::Percent(100.));
+        column.insert(TokenStyle {
+            background: ColorToken::SurfaceVariant.into(),
+            ..default()
+        });
 
         column.row(|row| {
-            row.style()
-                .height(Val::Px(30.))
-                .background_color(Color::rgb(0.1, 0.1, 0.1));
+            row.style().height(Val::Px(30.));
+            row.insert(TokenStyle {
+                background: ColorToken::Surface.into(),
+                ..default()
+            });
 
             row.menu(
                 MenuConfig {
@@ -217,6 +231,13 @@ fn setup(
                     })
                     .insert(Page::Playground);
 
+                    menu.menu_item_separator();
+                    menu.toggle_menu_item(ToggleMenuItemConfig {
+                        name: "Light Scheme".into(),
+                        ..default()
+                    })
+                    .insert(LightSchemeToggle);
+
                     menu.menu_item_separator();
                     menu.menu_item(MenuItemConfig {
                         name: "Exit".into(),
@@ -249,27 +270,46 @@ fn setup(
 }
 
 fn exit_app_on_menu_item(
-    q_menu_items: Query<&MenuItem, (With<ExitAppButton>, Changed<MenuItem>)>,
+    mut e_activated: EventReader<MenuItemActivated>,
+    q_menu_items: Query<Entity, With<ExitAppButton>>,
     q_windows: Query<Entity, With<Window>>,
     mut commands: Commands,
 ) {
-    let Ok(item) = q_menu_items.get_single() else {
-        return;
-    };
-
-    if item.interacted() {
+    if e_activated
+        .read()
+        .any(|activated| q_menu_items.contains(activated.entity))
+    {
         for entity in &q_windows {
             commands.entity(entity).remove::<Window>();
         }
     }
 }
 
+fn update_color_scheme(
+    q_toggles: Query<&ToggleMenuItem, (With<LightSchemeToggle>, Changed<ToggleMenuItem>)>,
+    mut tokens: ResMut<ThemeTokens>,
+) {
+    let Ok(toggle) = q_toggles.get_single() else {
+        return;
+    };
+
+    let scheme = match toggle.checked {
+        true => ColorScheme::Light,
+        false => ColorScheme::Dark,
+    };
+
+    if tokens.scheme != scheme {
+        tokens.scheme = scheme;
+    }
+}
+
 fn update_current_page(
     mut next_state: ResMut<NextState<Page>>,
-    q_menu_items: Query<(&Page, &MenuItem), Changed<MenuItem>>,
+    mut e_activated: EventReader<MenuItemActivated>,
+    q_menu_items: Query<&Page>,
 ) {
-    for (menu_type, menu_item) in &q_menu_items {
-        if menu_item.interacted() {
+    for activated in e_activated.read() {
+        if let Ok(menu_type) = q_menu_items.get(activated.entity) {
             next_state.set(*menu_type);
         }
     }
@@ -284,7 +324,7 @@ fn clear_content_on_menu_change(
     let extra_menu = q_extra_menu.single();
     commands.entity(root_entity).despawn_descendants();
     commands.entity(extra_menu).despawn_descendants();
-    commands.set_cursor(CursorIcon::Default);
+    commands.entity(root_entity).set_cursor(CursorIcon::Default);
 }
 
 fn spawn_hierarchy_view(
@@ -1,9 +1,14 @@
 use std::marker::PhantomData;
 
 use bevy::prelude::*;
-use sickle_math::ease::{Ease, ValueEasing};
+use serde::Deserialize;
+use sickle_math::{
+    color::ColorSpace,
+    ease::{Ease, ValueEasing},
+    spring::Spring,
+};
 
-use crate::{FluxInteraction, FluxInteractionStopwatch, FluxInteractionUpdate};
+use crate::{interactions::StyleState, FluxInteraction, FluxInteractionUpdate};
 
 pub struct AnimatedInteractionPlugin;
 
@@ -19,7 +24,6 @@ impl Plugin for AnimatedInteractionPlugin {
 #[derive(SystemSet, Clone, Debug, Eq, Hash, PartialEq)]
 pub struct AnimatedInteractionUpdate;
 
-// TODO: Add support for continous animations, i.e. loop, ping-pong
 #[derive(Clone, Copy, Debug, Default, Reflect)]
 pub enum AnimationProgress {
     #[default]
@@ -28,12 +32,165 @@ pub enum AnimationProgress {
     End,
 }
 
-#[derive(Clone, Copy, Debug, Default, Reflect)]
+impl AnimationProgress {
+    pub fn ratio(&self) -> f32 {
+        match self {
+            AnimationProgress::Start => 0.,
+            AnimationProgress::Inbetween(ratio) => *ratio,
+            AnimationProgress::End => 1.,
+        }
+    }
+}
+
+/// How often an animation is played before it finishes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect, Deserialize)]
+pub enum AnimationLoop {
+    #[default]
+    Once,
+    /// Restarts from the beginning until played `n` times
+    Repeat(u32),
+    RepeatForever,
+    /// Alternates direction on every play. An even count finishes back at the start.
+    PingPong(u32),
+    PingPongForever,
+}
+
+impl AnimationLoop {
+    fn plays(&self) -> Option<u32> {
+        match self {
+            AnimationLoop::Once => Some(1),
+            AnimationLoop::Repeat(plays) | AnimationLoop::PingPong(plays) => Some((*plays).max(1)),
+            AnimationLoop::RepeatForever | AnimationLoop::PingPongForever => None,
+        }
+    }
+
+    fn is_ping_pong(&self) -> bool {
+        matches!(
+            self,
+            AnimationLoop::PingPong(_) | AnimationLoop::PingPongForever
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Reflect, Deserialize)]
+#[serde(default)]
 pub struct AnimationConfig {
     pub duration: f32,
     pub easing: Ease,
+    pub repeat: AnimationLoop,
     pub out_duration: Option<f32>,
     pub out_easing: Option<Ease>,
+    pub out_repeat: Option<AnimationLoop>,
+    /// Moves towards the end value with a spring instead of the timed curve.
+    /// Ignored by idle animations.
+    pub spring: Option<Spring>,
+    /// Overrides the color space colors are interpolated in
+    pub color_space: Option<ColorSpace>,
+}
+
+impl AnimationConfig {
+    /// Progress of playing the in timing on its own, outside of interactions
+    pub(crate) fn progress(&self, elapsed: f32) -> AnimationProgress {
+        self.in_tween().progress(elapsed)
+    }
+
+    pub(crate) fn is_finished(&self, elapsed: f32) -> bool {
+        self.in_tween().is_finished(elapsed)
+    }
+
+    pub(crate) fn wrap_elapsed(&self, elapsed: f32) -> f32 {
+        self.in_tween().wrap(elapsed)
+    }
+
+    fn in_tween(&self) -> Tween {
+        Tween {
+            duration: self.duration.max(0.),
+            easing: self.easing,
+            repeat: self.repeat,
+            spring: self.spring,
+            color_space: self.color_space,
+            delay: 0.,
+        }
+    }
+
+    fn out_tween(&self) -> Tween {
+        Tween {
+            duration: self.out_duration.unwrap_or(self.duration).max(0.),
+            easing: self.out_easing.unwrap_or(self.easing),
+            repeat: self.out_repeat.unwrap_or(self.repeat),
+            spring: self.spring,
+            color_space: self.color_space,
+            delay: 0.,
+        }
+    }
+}
+
+/// The resolved timing of a single transition
+#[derive(Clone, Copy, Debug)]
+struct Tween {
+    duration: f32,
+    easing: Ease,
+    repeat: AnimationLoop,
+    spring: Option<Spring>,
+    color_space: Option<ColorSpace>,
+    delay: f32,
+}
+
+impl Tween {
+    fn progress(&self, elapsed: f32) -> AnimationProgress {
+        if elapsed < self.delay {
+            return AnimationProgress::Start;
+        }
+
+        if self.duration == 0. {
+            return AnimationProgress::End;
+        }
+
+        let elapsed = elapsed - self.delay;
+        let play = (elapsed / self.duration).floor();
+        if let Some(plays) = self.repeat.plays() {
+            if play >= plays as f32 {
+                return match self.repeat.is_ping_pong() && plays % 2 == 0 {
+                    true => AnimationProgress::Start,
+                    false => AnimationProgress::End,
+                };
+            }
+        }
+
+        let ratio = ((elapsed - play * self.duration) / self.duration).clamp(0., 1.);
+        let ratio = match self.repeat.is_ping_pong() && play % 2. == 1. {
+            true => 1. - ratio,
+            false => ratio,
+        };
+
+        AnimationProgress::Inbetween(ratio.ease(self.easing))
+    }
+
+    fn is_finished(&self, elapsed: f32) -> bool {
+        if elapsed < self.delay {
+            return false;
+        }
+
+        match self.repeat.plays() {
+            _ if self.duration == 0. => true,
+            Some(plays) => elapsed - self.delay >= self.duration * plays as f32,
+            None => false,
+        }
+    }
+
+    /// Keeps the elapsed time of endless loops within a single cycle
+    fn wrap(&self, elapsed: f32) -> f32 {
+        let period = match self.repeat {
+            AnimationLoop::RepeatForever => self.duration,
+            AnimationLoop::PingPongForever => self.duration * 2.,
+            _ => return elapsed,
+        };
+
+        match period > 0. && elapsed >= self.delay + period {
+            true => self.delay + (elapsed - self.delay) % period,
+            false => elapsed,
+        }
+    }
 }
 
 #[derive(Component, Clone, Copy, Debug, Default, Reflect)]
@@ -41,17 +198,117 @@ pub struct AnimationConfig {
 pub struct AnimatedInteractionState<T: Component + Default + Reflect> {
     pub context: Option<T>,
     pub progress: AnimationProgress,
+    pub from: StyleState,
+    pub to: StyleState,
+    /// The transition left the pressed state by releasing outside of the node
+    pub canceled: bool,
+    pub elapsed: f32,
+    /// The transition played to its end, only the idle animation updates
+    pub finished: bool,
+    /// Progress of the idle animation. Set once a transition to the default state finished.
+    pub idle_progress: Option<AnimationProgress>,
+    pub idle_elapsed: f32,
+    /// Change of the progress per second, kept when a transition is interrupted
+    pub velocity: f32,
+    /// The color space of the playing transition or idle animation
+    pub color_space: ColorSpace,
 }
 
-#[derive(Component, Clone, Copy, Debug, Reflect)]
+impl<T: Component + Default + Reflect> AnimatedInteractionState<T> {
+    pub fn start_transition(&mut self, from: StyleState, to: StyleState, canceled: bool) {
+        // The new transition starts from the current value, so reversing midway
+        // rescales the velocity to the shorter way back
+        let ratio = self.progress.ratio();
+        self.velocity = match (canceled, to == self.from) {
+            (true, _) => 0.,
+            (false, true) if ratio > 0. => -self.velocity / ratio,
+            (false, true) => 0.,
+            (false, false) => self.velocity,
+        };
+
+        self.from = from;
+        self.to = to;
+        self.canceled = canceled;
+        self.elapsed = 0.;
+        self.progress = AnimationProgress::Start;
+        self.finished = false;
+        self.idle_progress = None;
+        self.idle_elapsed = 0.;
+    }
+
+    /// Advances the playing transition, or the idle animation once the transition finished
+    pub(crate) fn update(&mut self, animation: &AnimatedInteraction<T>, delta: f32) {
+        if !self.finished {
+            let tween = animation.transition_tween(self.from, self.to, self.canceled);
+            self.elapsed = tween.wrap(self.elapsed + delta);
+            let (progress, velocity, finished) = match tween.spring {
+                Some(spring) if self.elapsed >= tween.delay => {
+                    let (position, velocity) =
+                        spring.step(self.progress.ratio(), self.velocity, 1., delta);
+
+                    match spring.is_at_rest(position, velocity, 1.) {
+                        true => (AnimationProgress::End, 0., true),
+                        false => (AnimationProgress::Inbetween(position), velocity, false),
+                    }
+                }
+                // The velocity carries over once the delay passed
+                Some(_) => (AnimationProgress::Start, self.velocity, false),
+                None => {
+                    let progress = tween.progress(self.elapsed);
+                    let finished = tween.is_finished(self.elapsed);
+                    let velocity = match finished || delta == 0. {
+                        true => 0.,
+                        false => (progress.ratio() - self.progress.ratio()) / delta,
+                    };
+
+                    (progress, velocity, finished)
+                }
+            };
+
+            self.progress = progress;
+            self.velocity = velocity;
+            self.finished = finished;
+            self.color_space = tween.color_space.unwrap_or(animation.color_space);
+            return;
+        }
+
+        let Some(idle) = animation.idle else {
+            return;
+        };
+
+        if self.to != StyleState::Default {
+            return;
+        }
+
+        let tween = idle.in_tween();
+        if self.idle_progress.is_none() {
+            self.idle_elapsed = 0.;
+        } else if tween.is_finished(self.idle_elapsed) {
+            return;
+        } else {
+            self.idle_elapsed = tween.wrap(self.idle_elapsed + delta);
+        }
+
+        self.idle_progress = tween.progress(self.idle_elapsed).into();
+        self.color_space = tween.color_space.unwrap_or(animation.color_space);
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Reflect, Deserialize)]
 #[reflect(Component)]
+#[serde(default, bound = "")]
 pub struct AnimatedInteraction<T: Component> {
+    #[serde(skip)]
     pub context: PhantomData<T>,
     pub tween: AnimationConfig,
     pub hover: Option<AnimationConfig>,
     pub press: Option<AnimationConfig>,
     pub cancel: Option<AnimationConfig>,
     pub reset_delay: Option<f32>,
+    /// Played while no interaction is active, between the original and the idle value
+    pub idle: Option<AnimationConfig>,
+    /// Color space used by tweens that don't set their own
+    pub color_space: ColorSpace,
 }
 
 impl<T: Component> Default for AnimatedInteraction<T> {
@@ -75,6 +332,54 @@ impl<T: Component> Default for AnimatedInteraction<T> {
             }
             .into(),
             reset_delay: Default::default(),
+            idle: Default::default(),
+            color_space: Default::default(),
+        }
+    }
+}
+
+impl<T: Component> AnimatedInteraction<T> {
+    /// Progress of the transition between two style states.
+    ///
+    /// Entering a state uses its tween's in timing, leaving it uses the out timing.
+    /// Press and hover transitions use their own tweens if set, everything else the base tween.
+    pub fn transition_progress(
+        &self,
+        from: StyleState,
+        to: StyleState,
+        canceled: bool,
+        elapsed: f32,
+    ) -> AnimationProgress {
+        self.transition_tween(from, to, canceled).progress(elapsed)
+    }
+
+    fn transition_tween(&self, from: StyleState, to: StyleState, canceled: bool) -> Tween {
+        if canceled {
+            let tween = self.cancel.unwrap_or(self.tween);
+            let reset_delay = self.reset_delay.unwrap_or(tween.duration).max(0.);
+
+            return Tween {
+                delay: reset_delay,
+                ..tween.out_tween()
+            };
+        }
+
+        let (tween, entering) = if to == StyleState::Pressed {
+            (self.press, true)
+        } else if from == StyleState::Pressed {
+            (self.press, false)
+        } else if to == StyleState::Hover {
+            (self.hover, true)
+        } else if from == StyleState::Hover {
+            (self.hover, false)
+        } else {
+            (None, to > from)
+        };
+
+        let tween = tween.unwrap_or(self.tween);
+        match entering {
+            true => tween.in_tween(),
+            false => tween.out_tween(),
         }
     }
 }
@@ -98,108 +403,10 @@ pub fn add_animated_interaction_state<T: Component + Default + Reflect>(
 }
 
 pub fn update_animated_interaction_state<T: Component + Default + Reflect>(
-    mut q_interaction: Query<(
-        &AnimatedInteraction<T>,
-        &FluxInteraction,
-        &FluxInteractionStopwatch,
-        &mut AnimatedInteractionState<T>,
-    )>,
+    time: Res<Time<Real>>,
+    mut q_interaction: Query<(&AnimatedInteraction<T>, &mut AnimatedInteractionState<T>)>,
 ) {
-    for (animation, interaction, stopwatch, mut animation_state) in &mut q_interaction {
-        let (base_tween, hover, press, cancel) = (
-            animation.tween,
-            animation.hover,
-            animation.press,
-            animation.cancel,
-        );
-
-        let elapsed = stopwatch.0.elapsed_secs();
-
-        let progress = match *interaction {
-            FluxInteraction::Pressed => {
-                let tween = press.unwrap_or(base_tween);
-                let tween_time = tween.duration.max(0.);
-
-                if tween_time == 0. {
-                    AnimationProgress::End
-                } else {
-                    let tween_ratio = (elapsed / tween_time).clamp(0., 1.).ease(tween.easing);
-                    AnimationProgress::Inbetween(tween_ratio)
-                }
-            }
-            FluxInteraction::Released => {
-                let tween = press.unwrap_or(base_tween);
-                let tween_time = tween.out_duration.unwrap_or(tween.duration).max(0.);
-
-                if tween_time == 0. {
-                    AnimationProgress::End
-                } else {
-                    let easing = tween.out_easing.unwrap_or(tween.easing);
-                    let tween_ratio = (elapsed / tween_time).clamp(0., 1.).ease(easing);
-
-                    if tween_ratio == 1. {
-                        AnimationProgress::End
-                    } else {
-                        AnimationProgress::Inbetween(tween_ratio)
-                    }
-                }
-            }
-            FluxInteraction::PressCanceled => {
-                let tween = cancel.unwrap_or(base_tween);
-                let tween_time = tween.duration.max(0.);
-
-                let reset_delay = animation.reset_delay.unwrap_or(tween_time).max(0.);
-                let reset_length = tween.out_duration.unwrap_or(tween_time).max(0.);
-
-                if elapsed < reset_delay {
-                    AnimationProgress::Start
-                } else {
-                    let easing = tween.out_easing.unwrap_or(tween.easing);
-                    let tween_ratio = ((elapsed - reset_delay) / reset_length)
-                        .clamp(0., 1.)
-                        .ease(easing);
-
-                    if tween_time == 0. || tween_ratio == 1. {
-                        AnimationProgress::End
-                    } else {
-                        AnimationProgress::Inbetween(tween_ratio)
-                    }
-                }
-            }
-            FluxInteraction::PointerEnter => {
-                let tween = hover.unwrap_or(base_tween);
-                let tween_time = tween.duration.max(0.);
-
-                if tween_time == 0. {
-                    AnimationProgress::End
-                } else {
-                    let tween_ratio = (elapsed / tween_time).clamp(0., 1.).ease(tween.easing);
-                    if tween_ratio == 1. {
-                        AnimationProgress::End
-                    } else {
-                        AnimationProgress::Inbetween(tween_ratio)
-                    }
-                }
-            }
-            FluxInteraction::PointerLeave => {
-                let tween = hover.unwrap_or(base_tween);
-                let tween_time = tween.out_duration.unwrap_or(tween.duration).max(0.);
-
-                if tween_time == 0. {
-                    AnimationProgress::End
-                } else {
-                    let easing = tween.out_easing.unwrap_or(tween.easing);
-                    let tween_ratio = (elapsed / tween_time).clamp(0., 1.).ease(easing);
-                    if tween_ratio == 1. {
-                        AnimationProgress::End
-                    } else {
-                        AnimationProgress::Inbetween(tween_ratio)
-                    }
-                }
-            }
-            _ => AnimationProgress::End,
-        };
-
-        animation_state.progress = progress;
+    for (animation, mut animation_state) in &mut q_interaction {
+        animation_state.update(animation, time.delta_seconds());
     }
 }
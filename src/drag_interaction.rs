@@ -1,10 +1,14 @@
 use bevy::{
+    input::touch::{TouchInput, TouchPhase},
     prelude::*,
-    window::{CursorGrabMode, PrimaryWindow},
+    window::CursorGrabMode,
 };
 use bevy_reflect::Reflect;
 
-use crate::{FluxInteraction, FluxInteractionUpdate};
+use crate::{
+    node_window::{NodePicker, NodeWindow},
+    FluxInteraction, FluxInteractionUpdate,
+};
 
 pub struct DragInteractionPlugin;
 
@@ -16,6 +20,7 @@ impl Plugin for DragInteractionPlugin {
                 (
                     update_drag_progress,
                     update_drag_state,
+                    update_touch_drag_state,
                     update_cursor_confinement_from_drag,
                 )
                     .chain()
@@ -38,6 +43,15 @@ pub struct Draggable {
 }
 
 impl Draggable {
+    /// The touch pointer currently dragging this node, if any
+    pub fn touch_id(&self) -> Option<u64> {
+        match (self.state, self.source) {
+            (DragState::Inactive, _) => None,
+            (_, DragSource::Touch(id)) => id.into(),
+            _ => None,
+        }
+    }
+
     fn clear(&mut self) {
         self.origin = None;
         self.position = None;
@@ -66,44 +80,50 @@ pub enum DragSource {
 }
 
 fn update_cursor_confinement_from_drag(
-    q_draggable: Query<&Draggable, Changed<Draggable>>,
-    mut q_window: Query<&mut Window, With<PrimaryWindow>>,
+    q_draggable: Query<(Entity, &Draggable), Changed<Draggable>>,
+    node_window: NodeWindow,
+    mut q_window: Query<&mut Window>,
 ) {
-    let Ok(mut window) = q_window.get_single_mut() else {
-        return;
-    };
+    for (entity, draggable) in &q_draggable {
+        if draggable.source != DragSource::Mouse {
+            continue;
+        }
 
-    if let Some(_) = q_draggable
-        .iter()
-        .find(|&draggable| draggable.state == DragState::DragStart)
-    {
-        window.cursor.grab_mode = CursorGrabMode::Confined;
-    } else if let Some(_) = q_draggable.iter().find(|&draggable| {
-        draggable.state == DragState::DragEnd || draggable.state == DragState::DragCanceled
-    }) {
-        window.cursor.grab_mode = CursorGrabMode::None;
+        let grab_mode = match draggable.state {
+            DragState::DragStart => CursorGrabMode::Confined,
+            DragState::DragEnd | DragState::DragCanceled => CursorGrabMode::None,
+            _ => continue,
+        };
+
+        let Some(window) = node_window.window(entity) else {
+            continue;
+        };
+
+        let Ok(mut window) = q_window.get_mut(window) else {
+            continue;
+        };
+
+        if window.cursor.grab_mode != grab_mode {
+            window.cursor.grab_mode = grab_mode;
+        }
     }
 }
 
 // TODO: Consider using MouseMotion and TouchInput events directly
-// TODO: Remove dependency on PrimaryWindow
 fn update_drag_progress(
-    mut q_draggable: Query<(&mut Draggable, &FluxInteraction)>,
-    q_window: Query<&Window, With<PrimaryWindow>>,
+    mut q_draggable: Query<(Entity, &mut Draggable, &FluxInteraction)>,
+    node_window: NodeWindow,
+    q_window: Query<&Window>,
     r_touches: Res<Touches>,
     r_keys: Res<ButtonInput<KeyCode>>,
 ) {
-    let Ok(window) = q_window.get_single() else {
-        return;
-    };
-
-    for (mut draggable, flux_interaction) in &mut q_draggable {
+    for (entity, mut draggable, flux_interaction) in &mut q_draggable {
         if draggable.state == DragState::DragEnd {
             draggable.state = DragState::Inactive;
             draggable.clear();
         } else if draggable.state == DragState::DragCanceled {
             draggable.state = DragState::Inactive;
-        } else if *flux_interaction == FluxInteraction::Pressed
+        } else if is_pointer_pressed(&draggable, flux_interaction, &r_touches)
             && (draggable.state == DragState::MaybeDragged
                 || draggable.state == DragState::DragStart
                 || draggable.state == DragState::Dragging)
@@ -122,7 +142,10 @@ fn update_drag_progress(
             }
 
             let position: Option<Vec2> = match draggable.source {
-                DragSource::Mouse => window.cursor_position(),
+                DragSource::Mouse => node_window
+                    .window(entity)
+                    .and_then(|window| q_window.get(window).ok())
+                    .and_then(|window| window.cursor_position()),
                 DragSource::Touch(id) => match r_touches.get_pressed(id) {
                     Some(touch) => touch.position().into(),
                     None => None,
@@ -146,26 +169,49 @@ fn update_drag_progress(
     }
 }
 
+fn is_pointer_pressed(
+    draggable: &Draggable,
+    flux_interaction: &FluxInteraction,
+    r_touches: &Touches,
+) -> bool {
+    match draggable.source {
+        DragSource::Mouse => *flux_interaction == FluxInteraction::Pressed,
+        DragSource::Touch(id) => r_touches.get_pressed(id).is_some(),
+    }
+}
+
 fn update_drag_state(
-    mut q_draggable: Query<(&mut Draggable, &FluxInteraction), Changed<FluxInteraction>>,
-    q_window: Query<&Window, With<PrimaryWindow>>,
-    r_touches: Res<Touches>,
+    mut q_draggable: Query<(Entity, &mut Draggable, &FluxInteraction), Changed<FluxInteraction>>,
+    node_window: NodeWindow,
+    q_window: Query<&Window>,
+    r_mouse: Res<ButtonInput<MouseButton>>,
 ) {
-    for (mut draggable, flux_interaction) in &mut q_draggable {
+    // Touches are tracked per finger in update_touch_drag_state, the flux interaction
+    // of a touched node only follows the first finger.
+    for (entity, mut draggable, flux_interaction) in &mut q_draggable {
+        if draggable.touch_id().is_some() {
+            continue;
+        }
+
         if *flux_interaction == FluxInteraction::Pressed
             && draggable.state != DragState::MaybeDragged
         {
-            let mut drag_source = DragSource::Mouse;
-            let mut position = q_window.single().cursor_position();
-            if position.is_none() {
-                position = r_touches.first_pressed_position();
-                drag_source = DragSource::Touch(r_touches.iter().next().unwrap().id());
+            if !r_mouse.pressed(MouseButton::Left) {
+                continue;
             }
 
+            let Some(position) = node_window
+                .window(entity)
+                .and_then(|window| q_window.get(window).ok())
+                .and_then(|window| window.cursor_position())
+            else {
+                continue;
+            };
+
             draggable.state = DragState::MaybeDragged;
-            draggable.source = drag_source;
-            draggable.origin = position;
-            draggable.position = position;
+            draggable.source = DragSource::Mouse;
+            draggable.origin = position.into();
+            draggable.position = position.into();
             draggable.diff = Vec2::default().into();
         } else if *flux_interaction == FluxInteraction::Released
             || *flux_interaction == FluxInteraction::PressCanceled
@@ -179,3 +225,59 @@ fn update_drag_state(
         }
     }
 }
+
+fn update_touch_drag_state(
+    mut e_touch_input: EventReader<TouchInput>,
+    r_touches: Res<Touches>,
+    node_picker: NodePicker,
+    mut q_draggable: Query<(&mut Draggable, &FluxInteraction)>,
+) {
+    for touch in e_touch_input
+        .read()
+        .filter(|touch| touch.phase == TouchPhase::Started)
+    {
+        // Each finger drags the top-most draggable under it
+        let Some((mut draggable, flux_interaction)) = node_picker
+            .pick(touch.window, touch.position)
+            .into_iter()
+            .find(|entity| q_draggable.contains(*entity))
+            .and_then(|entity| q_draggable.get_mut(entity).ok())
+        else {
+            continue;
+        };
+
+        if draggable.state != DragState::Inactive || *flux_interaction == FluxInteraction::Disabled
+        {
+            continue;
+        }
+
+        draggable.state = DragState::MaybeDragged;
+        draggable.source = DragSource::Touch(touch.id);
+        draggable.origin = touch.position.into();
+        draggable.position = touch.position.into();
+        draggable.diff = Vec2::default().into();
+    }
+
+    for (mut draggable, _) in &mut q_draggable {
+        let Some(id) = draggable.touch_id() else {
+            continue;
+        };
+
+        if r_touches.just_canceled(id) {
+            if draggable.state == DragState::DragStart || draggable.state == DragState::Dragging {
+                draggable.state = DragState::DragCanceled;
+            } else {
+                draggable.state = DragState::Inactive;
+            }
+
+            draggable.clear();
+        } else if r_touches.get_pressed(id).is_none() {
+            if draggable.state == DragState::DragStart || draggable.state == DragState::Dragging {
+                draggable.state = DragState::DragEnd;
+            } else if draggable.state == DragState::MaybeDragged {
+                draggable.state = DragState::Inactive;
+                draggable.clear();
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
-use bevy::{prelude::*, ui::RelativeCursorPosition};
+use bevy::{prelude::*, utils::HashMap};
 
-use crate::drag_interaction::{DragState, Draggable, DraggableUpdate};
+use crate::{
+    drag_interaction::{DragSource, DragState, Draggable, DraggableUpdate},
+    node_window::NodePicker,
+};
 
 pub struct DropInteractionPlugin;
 
@@ -31,6 +34,7 @@ fn update_drop_zone_single_frame_state(mut q_drop_zones: Query<&mut DropZone, Ch
             drop_zone.drop_phase = DropPhase::Inactive;
             drop_zone.incoming_droppable = None;
             drop_zone.position = None;
+            drop_zone.source = None;
         } else if drop_zone.drop_phase == DropPhase::DroppableEntered {
             drop_zone.drop_phase = DropPhase::DroppableHover
         }
@@ -46,38 +50,55 @@ fn should_update_drop_zones(
 }
 
 fn update_drop_zones(
-    q_droppables: Query<(Entity, &Draggable), (With<Droppable>, Changed<Draggable>)>,
-    q_drop_zone_data: Query<(Entity, &Interaction, &Node, &RelativeCursorPosition), With<DropZone>>,
+    q_droppables: Query<(Entity, &Draggable), With<Droppable>>,
+    node_picker: NodePicker,
     mut q_drop_zones: Query<(Entity, &mut DropZone)>,
 ) {
-    // Run condition makes sure we are dragging a droppable.
-    // We have no information if the interaction is from the same source,
-    // check if cursor is over any.
-    //
-    // Technically, a pointer / finger could be currently hovering the zone
-    // while another pointer / finger drags the droppable, but the ui_focus, flux and
-    // drag interactions only track main pointers.
-    let mut hovered_to_stack_index: Vec<(Entity, u32)> = q_drop_zone_data
-        .iter()
-        .filter(|(_, interaction, _, rel_pos)| {
-            **interaction == Interaction::Hovered && rel_pos.mouse_over()
-        })
-        .map(|(entity, _, node, _)| (entity, node.stack_index()))
-        .collect();
-
-    hovered_to_stack_index.sort_by_key(|(_, i)| *i);
-    if let Some((top_hovered, _)) = hovered_to_stack_index.last() {
-        // Safe unwrap: ID comes from a broader query of DropZones.
-        let (_, mut drop_zone) = q_drop_zones.get_mut(*top_hovered).unwrap();
-
-        // Take the first droppable that is moving
-        // ui_focus, flux and drag interactions only track the main pointer interaction
-        let (droppable_entity, draggable) = q_droppables
-            .iter()
-            .find(|(_, draggable)| {
-                draggable.state != DragState::Inactive && draggable.state != DragState::MaybeDragged
-            })
-            .unwrap();
+    // Every droppable is tracked by its own pointer / finger, so each one
+    // is matched against the top-most drop zone under its own position.
+    let mut hovered_zones: HashMap<Entity, (Entity, &Draggable)> = HashMap::new();
+    for (droppable_entity, draggable) in &q_droppables {
+        if draggable.state == DragState::Inactive || draggable.state == DragState::MaybeDragged {
+            continue;
+        }
+
+        let zone = if draggable.state == DragState::DragCanceled {
+            // Canceled drags have no position, notify the zone they were over
+            q_drop_zones
+                .iter()
+                .find(|(_, drop_zone)| drop_zone.incoming_droppable == Some(droppable_entity))
+                .map(|(entity, _)| entity)
+        } else {
+            let window = node_picker.node_window().window(droppable_entity);
+            match (window, draggable.position) {
+                (Some(window), Some(position)) => node_picker
+                    .pick(window, position)
+                    .into_iter()
+                    .find(|entity| q_drop_zones.contains(*entity)),
+                _ => None,
+            }
+        };
+
+        if let Some(zone) = zone {
+            hovered_zones
+                .entry(zone)
+                .or_insert((droppable_entity, draggable));
+        }
+    }
+
+    for (zone_entity, mut drop_zone) in &mut q_drop_zones {
+        let Some((droppable_entity, draggable)) = hovered_zones.get(&zone_entity) else {
+            if drop_zone.drop_phase == DropPhase::DroppableEntered
+                || drop_zone.drop_phase == DropPhase::DroppableHover
+            {
+                drop_zone.drop_phase = DropPhase::DroppableLeft;
+                drop_zone.incoming_droppable = None;
+                drop_zone.position = None;
+                drop_zone.source = None;
+            }
+
+            continue;
+        };
 
         // See update_drop_zone_single_frame_state which executes just before this system
         if drop_zone.drop_phase == DropPhase::Inactive {
@@ -92,36 +113,13 @@ fn update_drop_zones(
             || draggable.state == DragState::Dragging
             || draggable.state == DragState::DragEnd
         {
-            drop_zone.incoming_droppable = droppable_entity.into();
+            drop_zone.incoming_droppable = (*droppable_entity).into();
             drop_zone.position = draggable.position;
+            drop_zone.source = draggable.source.into();
         } else {
             drop_zone.incoming_droppable = None;
             drop_zone.position = None;
-        }
-
-        // Update all the other zones
-        for (dropzone_id, mut drop_zone) in &mut q_drop_zones {
-            if dropzone_id == *top_hovered {
-                continue;
-            }
-
-            if drop_zone.drop_phase == DropPhase::DroppableEntered
-                || drop_zone.drop_phase == DropPhase::DroppableHover
-            {
-                drop_zone.drop_phase = DropPhase::DroppableLeft;
-                drop_zone.incoming_droppable = None;
-                drop_zone.position = None;
-            }
-        }
-    } else {
-        for (_, mut drop_zone) in &mut q_drop_zones {
-            if drop_zone.drop_phase == DropPhase::DroppableEntered
-                || drop_zone.drop_phase == DropPhase::DroppableHover
-            {
-                drop_zone.drop_phase = DropPhase::DroppableLeft;
-                drop_zone.incoming_droppable = None;
-                drop_zone.position = None;
-            }
+            drop_zone.source = None;
         }
     }
 }
@@ -146,6 +144,7 @@ pub struct DropZone {
     drop_phase: DropPhase,
     incoming_droppable: Option<Entity>,
     position: Option<Vec2>,
+    source: Option<DragSource>,
 }
 
 impl DropZone {
@@ -160,4 +159,9 @@ impl DropZone {
     pub fn position(&self) -> Option<Vec2> {
         self.position
     }
+
+    /// The pointer dragging the incoming droppable
+    pub fn source(&self) -> Option<DragSource> {
+        self.source
+    }
 }
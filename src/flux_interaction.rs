@@ -1,4 +1,4 @@
-use bevy::{prelude::*, time::Stopwatch};
+use bevy::{ecs::system::SystemParam, prelude::*, time::Stopwatch};
 use bevy_reflect::Reflect;
 
 pub struct FluxInteractionPlugin;
@@ -6,12 +6,17 @@ pub struct FluxInteractionPlugin;
 impl Plugin for FluxInteractionPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<FluxInteractionConfig>()
+            .add_event::<Clicked>()
+            .add_event::<DoubleClicked>()
+            .add_event::<LongPressed>()
+            .add_event::<PressAndHoldRepeat>()
             .configure_sets(Update, FluxInteractionUpdate)
             .add_systems(
                 Update,
                 (
                     tick_flux_interaction_stopwatch,
                     update_flux_interaction,
+                    update_flux_gestures,
                     reset_stopwatch_on_change,
                     update_prev_interaction,
                 )
@@ -25,12 +30,21 @@ impl Plugin for FluxInteractionPlugin {
 #[derive(Resource, Clone, Debug, Reflect)]
 pub struct FluxInteractionConfig {
     pub max_interaction_duration: f32,
+    /// Max time between a release and the next press to count as a double click
+    pub double_click_interval: f32,
+    pub long_press_duration: f32,
+    pub press_and_hold_repeat_delay: f32,
+    pub press_and_hold_repeat_interval: f32,
 }
 
 impl Default for FluxInteractionConfig {
     fn default() -> Self {
         Self {
             max_interaction_duration: 1.,
+            double_click_interval: 0.3,
+            long_press_duration: 0.5,
+            press_and_hold_repeat_delay: 0.5,
+            press_and_hold_repeat_interval: 0.1,
         }
     }
 }
@@ -43,6 +57,7 @@ pub struct TrackedInteraction {
     pub interaction: FluxInteraction,
     pub prev_interaction: PrevInteraction,
     pub stopwatch: FluxInteractionStopwatch,
+    pub gesture_state: FluxGestureState,
 }
 
 #[derive(Component, Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
@@ -65,6 +80,40 @@ pub enum FluxInteraction {
 #[component(storage = "SparseSet")]
 pub struct FluxInteractionStopwatch(pub Stopwatch);
 
+/// Tracks the press in progress to derive gesture events
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct FluxGestureState {
+    released: bool,
+    double_click: bool,
+    long_pressed: bool,
+    repeat_count: u32,
+}
+
+/// Press released over the node. Not sent if the press became a long press.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct Clicked {
+    pub entity: Entity,
+}
+
+/// Sent along the second [`Clicked`] of a quick succession
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DoubleClicked {
+    pub entity: Entity,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct LongPressed {
+    pub entity: Entity,
+}
+
+/// Sent repeatedly while a press is held, after an initial delay
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PressAndHoldRepeat {
+    pub entity: Entity,
+    pub repeat_count: u32,
+}
+
 #[derive(Component, Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
 #[reflect(Component, PartialEq)]
 pub enum PrevInteraction {
@@ -77,11 +126,14 @@ pub enum PrevInteraction {
 fn tick_flux_interaction_stopwatch(
     config: Res<FluxInteractionConfig>,
     time: Res<Time<Real>>,
-    mut q_stopwatch: Query<(Entity, &mut FluxInteractionStopwatch)>,
+    mut q_stopwatch: Query<(Entity, &mut FluxInteractionStopwatch, &FluxInteraction)>,
     mut commands: Commands,
 ) {
-    for (entity, mut stopwatch) in &mut q_stopwatch {
-        if stopwatch.0.elapsed().as_secs_f32() > config.max_interaction_duration {
+    for (entity, mut stopwatch, flux_interaction) in &mut q_stopwatch {
+        // Held presses keep their stopwatch for long press and repeat
+        if *flux_interaction != FluxInteraction::Pressed
+            && stopwatch.0.elapsed().as_secs_f32() > config.max_interaction_duration
+        {
             commands.entity(entity).remove::<FluxInteractionStopwatch>();
         } else {
             stopwatch.0.tick(time.delta());
@@ -116,6 +168,78 @@ fn update_flux_interaction(
     }
 }
 
+#[derive(SystemParam)]
+struct FluxGestureEvents<'w> {
+    clicked: EventWriter<'w, Clicked>,
+    double_clicked: EventWriter<'w, DoubleClicked>,
+    long_pressed: EventWriter<'w, LongPressed>,
+    repeat: EventWriter<'w, PressAndHoldRepeat>,
+}
+
+// Runs before the stopwatch is reset, so on change it still holds the
+// duration of the previous flux interaction
+fn update_flux_gestures(
+    config: Res<FluxInteractionConfig>,
+    mut q_interaction: Query<(
+        Entity,
+        Ref<FluxInteraction>,
+        Option<&FluxInteractionStopwatch>,
+        &mut FluxGestureState,
+    )>,
+    mut events: FluxGestureEvents,
+) {
+    for (entity, flux_interaction, stopwatch, mut state) in &mut q_interaction {
+        let elapsed = stopwatch.map(|stopwatch| stopwatch.0.elapsed_secs());
+
+        if flux_interaction.is_changed() {
+            if *flux_interaction == FluxInteraction::Pressed {
+                let double_click = state.released
+                    && elapsed.is_some_and(|elapsed| elapsed <= config.double_click_interval);
+
+                *state = FluxGestureState {
+                    double_click,
+                    ..default()
+                };
+            } else if *flux_interaction == FluxInteraction::Released {
+                let clicked = !state.long_pressed;
+                if clicked {
+                    events.clicked.send(Clicked { entity });
+                    if state.double_click {
+                        events.double_clicked.send(DoubleClicked { entity });
+                    }
+                }
+
+                // A third click starts a new double click
+                *state = FluxGestureState {
+                    released: clicked && !state.double_click,
+                    ..default()
+                };
+            } else {
+                *state = FluxGestureState::default();
+            }
+        } else if *flux_interaction == FluxInteraction::Pressed {
+            let Some(elapsed) = elapsed else {
+                continue;
+            };
+
+            if !state.long_pressed && elapsed >= config.long_press_duration {
+                state.long_pressed = true;
+                events.long_pressed.send(LongPressed { entity });
+            }
+
+            let next_repeat = config.press_and_hold_repeat_delay
+                + state.repeat_count as f32 * config.press_and_hold_repeat_interval;
+            if elapsed >= next_repeat {
+                state.repeat_count += 1;
+                events.repeat.send(PressAndHoldRepeat {
+                    entity,
+                    repeat_count: state.repeat_count,
+                });
+            }
+        }
+    }
+}
+
 fn reset_stopwatch_on_change(
     mut q_stopwatch: Query<
         (Entity, Option<&mut FluxInteractionStopwatch>),
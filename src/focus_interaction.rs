@@ -0,0 +1,476 @@
+use bevy::{ecs::system::SystemParam, input::InputSystem, prelude::*, ui::UiSystem};
+
+use crate::{
+    interactions::{set_pseudo_state, PseudoState, PseudoStates},
+    theme::tokens::{ColorToken, ThemeTokens},
+    FluxInteraction,
+};
+
+const FOCUS_RING_WIDTH: f32 = 2.;
+const FOCUS_RING_OFFSET: f32 = 1.;
+
+// Penalty applied to the offset perpendicular to the navigation direction
+const NAVIGATION_CROSS_AXIS_WEIGHT: f32 = 2.;
+
+pub struct FocusInteractionPlugin;
+
+impl Plugin for FocusInteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FocusedEntity>()
+            .register_type::<Focusable>()
+            .register_type::<ConfineFocus>()
+            .register_type::<CaptureKeyboardInput>()
+            .register_type::<CaptureNavigationAxis>()
+            .configure_sets(
+                PreUpdate,
+                FocusInteractionUpdate
+                    .after(InputSystem)
+                    .after(UiSystem::Focus),
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    clear_removed_focus,
+                    focus_on_press,
+                    move_focus_on_key_press,
+                    move_focus_on_directional_input,
+                    activate_focused_on_input,
+                    update_focused_entity,
+                )
+                    .chain()
+                    .in_set(FocusInteractionUpdate),
+            );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct FocusInteractionUpdate;
+
+/// Marks an entity as keyboard focusable.
+///
+/// Tab / Shift+Tab cycles through visible, enabled focusables by ascending `tab_index`,
+/// entities with the same index are visited in hierarchy order. Negative indices are
+/// left out of keyboard and gamepad navigation, but can still be focused by pressing them.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Focusable {
+    pub tab_index: i32,
+}
+
+/// Keeps directional navigation within the entity's descendants while one of them is focused.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct ConfineFocus;
+
+/// The entity handles arrow keys, Space and Enter itself while focused (i.e. text inputs),
+/// they neither move the focus nor activate it.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct CaptureKeyboardInput;
+
+/// The entity handles directional navigation along the axis itself while focused (i.e. sliders),
+/// the cross axis still moves the focus.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum CaptureNavigationAxis {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl CaptureNavigationAxis {
+    fn captures(&self, direction: NavigationDirection) -> bool {
+        match self {
+            CaptureNavigationAxis::Horizontal => matches!(
+                direction,
+                NavigationDirection::Left | NavigationDirection::Right
+            ),
+            CaptureNavigationAxis::Vertical => {
+                matches!(
+                    direction,
+                    NavigationDirection::Up | NavigationDirection::Down
+                )
+            }
+        }
+    }
+}
+
+/// The entity receiving keyboard input, if any.
+///
+/// Focus arriving by navigation plays the hover interaction of the entity.
+/// Enter / Space (or gamepad South) on the focused entity drives its [`FluxInteraction`]
+/// through `Pressed` and `Released`, the same as a click. Escape (or gamepad East) clears it.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct FocusedEntity(pub Option<Entity>);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum NavigationDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl NavigationDirection {
+    fn vector(&self) -> Vec2 {
+        // UI coordinates grow downwards
+        match self {
+            NavigationDirection::Up => Vec2::NEG_Y,
+            NavigationDirection::Down => Vec2::Y,
+            NavigationDirection::Left => Vec2::NEG_X,
+            NavigationDirection::Right => Vec2::X,
+        }
+    }
+}
+
+/// Keyboard and gamepad input driving the focus
+#[derive(SystemParam)]
+pub(crate) struct NavigationInput<'w> {
+    keys: Res<'w, ButtonInput<KeyCode>>,
+    gamepads: Res<'w, Gamepads>,
+    gamepad_buttons: Res<'w, ButtonInput<GamepadButton>>,
+}
+
+impl<'w> NavigationInput<'w> {
+    const CONFIRM_KEYS: [KeyCode; 3] = [KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space];
+
+    fn gamepad_just_pressed(&self, button_type: GamepadButtonType) -> bool {
+        self.gamepads.iter().any(|gamepad| {
+            self.gamepad_buttons
+                .just_pressed(GamepadButton::new(gamepad, button_type))
+        })
+    }
+
+    fn gamepad_just_released(&self, button_type: GamepadButtonType) -> bool {
+        self.gamepads.iter().any(|gamepad| {
+            self.gamepad_buttons
+                .just_released(GamepadButton::new(gamepad, button_type))
+        })
+    }
+
+    pub(crate) fn direction(&self) -> Option<NavigationDirection> {
+        if self.keys.just_pressed(KeyCode::ArrowUp)
+            || self.gamepad_just_pressed(GamepadButtonType::DPadUp)
+        {
+            Some(NavigationDirection::Up)
+        } else if self.keys.just_pressed(KeyCode::ArrowDown)
+            || self.gamepad_just_pressed(GamepadButtonType::DPadDown)
+        {
+            Some(NavigationDirection::Down)
+        } else if self.keys.just_pressed(KeyCode::ArrowLeft)
+            || self.gamepad_just_pressed(GamepadButtonType::DPadLeft)
+        {
+            Some(NavigationDirection::Left)
+        } else if self.keys.just_pressed(KeyCode::ArrowRight)
+            || self.gamepad_just_pressed(GamepadButtonType::DPadRight)
+        {
+            Some(NavigationDirection::Right)
+        } else {
+            None
+        }
+    }
+
+    fn confirm_just_pressed(&self) -> bool {
+        self.keys.any_just_pressed(Self::CONFIRM_KEYS)
+            || self.gamepad_just_pressed(GamepadButtonType::South)
+    }
+
+    fn confirm_just_released(&self) -> bool {
+        self.keys.any_just_released(Self::CONFIRM_KEYS)
+            || self.gamepad_just_released(GamepadButtonType::South)
+    }
+
+    fn cancel_just_pressed(&self) -> bool {
+        self.keys.just_pressed(KeyCode::Escape)
+            || self.gamepad_just_pressed(GamepadButtonType::East)
+    }
+}
+
+#[derive(SystemParam)]
+struct FocusableTree<'w, 's> {
+    q_roots: Query<'w, 's, Entity, (With<Node>, Without<Parent>)>,
+    q_children: Query<'w, 's, &'static Children>,
+    q_parent: Query<'w, 's, &'static Parent>,
+    q_focusables: Query<'w, 's, (&'static Focusable, &'static InheritedVisibility)>,
+    q_flux_interaction: Query<'w, 's, &'static FluxInteraction>,
+    q_confine: Query<'w, 's, Entity, With<ConfineFocus>>,
+}
+
+impl<'w, 's> FocusableTree<'w, 's> {
+    /// Navigable entities under `root` (or the whole UI) in Tab order.
+    fn focus_order(&self, root: Option<Entity>) -> Vec<Entity> {
+        let mut focus_order: Vec<(Entity, i32)> = Vec::new();
+        match root {
+            Some(root) => self.collect_focusables(root, &mut focus_order),
+            None => {
+                for root in &self.q_roots {
+                    self.collect_focusables(root, &mut focus_order);
+                }
+            }
+        }
+
+        // Stable sort, keeps hierarchy order within the same index
+        focus_order.sort_by_key(|(_, tab_index)| *tab_index);
+        focus_order.into_iter().map(|(entity, _)| entity).collect()
+    }
+
+    fn collect_focusables(&self, entity: Entity, focus_order: &mut Vec<(Entity, i32)>) {
+        if let Ok((focusable, visibility)) = self.q_focusables.get(entity) {
+            if !visibility.get() {
+                return;
+            }
+
+            let disabled = self
+                .q_flux_interaction
+                .get(entity)
+                .is_ok_and(|flux_interaction| *flux_interaction == FluxInteraction::Disabled);
+            if focusable.tab_index >= 0 && !disabled {
+                focus_order.push((entity, focusable.tab_index));
+            }
+        }
+
+        if let Ok(children) = self.q_children.get(entity) {
+            for child in children {
+                self.collect_focusables(*child, focus_order);
+            }
+        }
+    }
+
+    fn confinement(&self, entity: Entity) -> Option<Entity> {
+        self.q_parent
+            .iter_ancestors(entity)
+            .find(|ancestor| self.q_confine.contains(*ancestor))
+    }
+}
+
+fn clear_removed_focus(
+    q_focusables: Query<(), With<Focusable>>,
+    mut focused: ResMut<FocusedEntity>,
+) {
+    if let Some(entity) = focused.0 {
+        if !q_focusables.contains(entity) {
+            focused.0 = None;
+        }
+    }
+}
+
+fn focus_on_press(
+    q_pressed: Query<(Entity, Ref<Interaction>, &Node), With<Focusable>>,
+    mut focused: ResMut<FocusedEntity>,
+) {
+    // Interactions pass through, focus the top-most pressed node
+    let pressed = q_pressed
+        .iter()
+        .filter(|(_, interaction, _)| {
+            interaction.is_changed() && **interaction == Interaction::Pressed
+        })
+        .max_by_key(|(_, _, node)| node.stack_index());
+
+    if let Some((entity, _, _)) = pressed {
+        if focused.0 != Some(entity) {
+            focused.0 = entity.into();
+        }
+    }
+}
+
+fn move_focus_on_key_press(
+    input: NavigationInput,
+    focus_tree: FocusableTree,
+    mut focused: ResMut<FocusedEntity>,
+) {
+    if input.cancel_just_pressed() && focused.0.is_some() {
+        focused.0 = None;
+        return;
+    }
+
+    if !input.keys.just_pressed(KeyCode::Tab) {
+        return;
+    }
+
+    let focus_order = focus_tree.focus_order(None);
+    if focus_order.is_empty() {
+        return;
+    }
+
+    let count = focus_order.len();
+    let backwards = input
+        .keys
+        .any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let current = focused
+        .0
+        .and_then(|entity| focus_order.iter().position(|e| *e == entity));
+
+    let next = match (current, backwards) {
+        (Some(index), false) => (index + 1) % count,
+        (Some(index), true) => (index + count - 1) % count,
+        (None, false) => 0,
+        (None, true) => count - 1,
+    };
+
+    focused.0 = focus_order[next].into();
+}
+
+fn move_focus_on_directional_input(
+    input: NavigationInput,
+    focus_tree: FocusableTree,
+    q_geometry: Query<(&Node, &GlobalTransform)>,
+    q_capture: Query<(), With<CaptureKeyboardInput>>,
+    q_capture_axis: Query<&CaptureNavigationAxis>,
+    mut focused: ResMut<FocusedEntity>,
+) {
+    let Some(direction) = input.direction() else {
+        return;
+    };
+
+    if focused.0.is_some_and(|entity| {
+        q_capture.contains(entity)
+            || q_capture_axis
+                .get(entity)
+                .is_ok_and(|axis| axis.captures(direction))
+    }) {
+        return;
+    }
+
+    let Some(current) = focused.0 else {
+        if let Some(first) = focus_tree.focus_order(None).first() {
+            focused.0 = (*first).into();
+        }
+        return;
+    };
+
+    let Ok((node, transform)) = q_geometry.get(current) else {
+        return;
+    };
+
+    let from = node.logical_rect(transform);
+    let axis = direction.vector();
+    let cross_axis = axis.perp();
+
+    let candidates = focus_tree.focus_order(focus_tree.confinement(current));
+    let mut closest: Option<(Entity, f32)> = None;
+    for candidate in candidates {
+        if candidate == current {
+            continue;
+        }
+
+        let Ok((node, transform)) = q_geometry.get(candidate) else {
+            continue;
+        };
+
+        let to = node.logical_rect(transform);
+        let offset = to.center() - from.center();
+        if offset.dot(axis) <= 0. {
+            continue;
+        }
+
+        // Gap between the facing edges, overlapping nodes count as adjacent
+        let edge_gap =
+            (offset.dot(axis).abs() - (from.half_size() + to.half_size()).dot(axis.abs())).max(0.);
+        let distance = edge_gap + offset.dot(cross_axis).abs() * NAVIGATION_CROSS_AXIS_WEIGHT;
+
+        match closest {
+            Some((_, closest_distance)) if closest_distance <= distance => (),
+            _ => closest = (candidate, distance).into(),
+        }
+    }
+
+    if let Some((entity, _)) = closest {
+        focused.0 = entity.into();
+    }
+}
+
+fn activate_focused_on_input(
+    input: NavigationInput,
+    focused: Res<FocusedEntity>,
+    q_capture: Query<(), With<CaptureKeyboardInput>>,
+    mut q_flux_interaction: Query<&mut FluxInteraction>,
+) {
+    let Some(entity) = focused.0 else {
+        return;
+    };
+
+    if q_capture.contains(entity) {
+        return;
+    }
+
+    let Ok(mut flux_interaction) = q_flux_interaction.get_mut(entity) else {
+        return;
+    };
+
+    if input.confirm_just_pressed()
+        && *flux_interaction != FluxInteraction::Disabled
+        && *flux_interaction != FluxInteraction::Pressed
+    {
+        *flux_interaction = FluxInteraction::Pressed;
+    } else if input.confirm_just_released() && *flux_interaction == FluxInteraction::Pressed {
+        *flux_interaction = FluxInteraction::Released;
+    }
+}
+
+fn update_focused_entity(
+    mut q_flux_interaction: Query<(&mut FluxInteraction, Option<&Interaction>)>,
+    mut q_pseudo_states: Query<&mut PseudoStates>,
+    mut q_outlines: Query<&mut Outline>,
+    focused: Res<FocusedEntity>,
+    tokens: Res<ThemeTokens>,
+    mut prev_focused: Local<Option<Entity>>,
+    mut commands: Commands,
+) {
+    if !focused.is_changed() && !tokens.is_changed() {
+        return;
+    }
+
+    let focus_changed = *prev_focused != focused.0;
+    if focus_changed {
+        if let Some(prev) = prev_focused.take() {
+            if let Ok(mut pseudo_states) = q_pseudo_states.get_mut(prev) {
+                set_pseudo_state(&mut pseudo_states, PseudoState::Focused, false);
+            }
+
+            // Hide rather than remove, as recommended for frequently toggled outlines
+            if let Ok(mut outline) = q_outlines.get_mut(prev) {
+                outline.color = Color::NONE;
+            }
+
+            // Leave the hover state focus put the entity in, unless the pointer is over it
+            if let Ok((mut flux_interaction, interaction)) = q_flux_interaction.get_mut(prev) {
+                if interaction != Some(&Interaction::Hovered)
+                    && matches!(
+                        *flux_interaction,
+                        FluxInteraction::PointerEnter | FluxInteraction::Released
+                    )
+                {
+                    *flux_interaction = FluxInteraction::PointerLeave;
+                }
+            }
+        }
+    }
+
+    let Some(entity) = focused.0 else {
+        return;
+    };
+
+    if let Ok(mut pseudo_states) = q_pseudo_states.get_mut(entity) {
+        set_pseudo_state(&mut pseudo_states, PseudoState::Focused, true);
+    }
+
+    if let (true, Ok((mut flux_interaction, _))) =
+        (focus_changed, q_flux_interaction.get_mut(entity))
+    {
+        if matches!(
+            *flux_interaction,
+            FluxInteraction::None | FluxInteraction::PointerLeave | FluxInteraction::PressCanceled
+        ) {
+            *flux_interaction = FluxInteraction::PointerEnter;
+        }
+    }
+
+    commands.entity(entity).insert(Outline::new(
+        Val::Px(FOCUS_RING_WIDTH),
+        Val::Px(FOCUS_RING_OFFSET),
+        tokens.color(ColorToken::Accent),
+    ));
+
+    *prev_focused = entity.into();
+}
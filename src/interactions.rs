@@ -1,20 +1,82 @@
-use bevy::prelude::*;
+pub mod reflect_path;
+
+use bevy::{prelude::*, ui::FocusPolicy};
+use serde::Deserialize;
 use sickle_macros::simple_interaction_for;
 use sickle_math::lerp::Lerp;
 
 use crate::{animated_interaction::*, FluxInteraction};
 
+use self::reflect_path::ReflectPathInteractionPlugin;
+
 pub struct InteractionsPlugin;
 
 impl Plugin for InteractionsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((
-            InteractiveBackground::default(),
-            InteractiveBorderSize::default(),
-            InteractiveBorderColor::default(),
-            InteractiveMargin::default(),
-            InteractiveHeight::default(),
-        ));
+        app.register_type::<PseudoStates>()
+            .register_type::<StateDelays>()
+            .add_plugins((
+                InteractiveBackground::default(),
+                InteractiveBorderSize::default(),
+                InteractiveBorderColor::default(),
+                InteractiveMargin::default(),
+                InteractiveHeight::default(),
+            ))
+            .add_plugins((
+                InteractiveDisplay::default(),
+                InteractivePositionType::default(),
+                InteractiveOverflow::default(),
+                InteractiveDirection::default(),
+                InteractiveLeft::default(),
+                InteractiveRight::default(),
+                InteractiveTop::default(),
+                InteractiveBottom::default(),
+                InteractiveWidth::default(),
+                InteractiveMinWidth::default(),
+                InteractiveMinHeight::default(),
+                InteractiveAspectRatio::default(),
+            ))
+            .add_plugins((
+                InteractiveAlignItems::default(),
+                InteractiveJustifyItems::default(),
+                InteractiveAlignSelf::default(),
+                InteractiveJustifySelf::default(),
+                InteractiveAlignContent::default(),
+                InteractiveJustifyContent::default(),
+                InteractivePadding::default(),
+                InteractiveFlexDirection::default(),
+                InteractiveFlexWrap::default(),
+                InteractiveFlexGrow::default(),
+                InteractiveFlexShrink::default(),
+                InteractiveFlexBasis::default(),
+            ))
+            .add_plugins((
+                InteractiveRowGap::default(),
+                InteractiveColumnGap::default(),
+                InteractiveGridAutoFlow::default(),
+                InteractiveGridTemplateRows::default(),
+                InteractiveGridTemplateColumns::default(),
+                InteractiveGridAutoRows::default(),
+                InteractiveGridAutoColumns::default(),
+                InteractiveGridRow::default(),
+                InteractiveGridColumn::default(),
+            ))
+            .add_plugins((
+                InteractiveFocusPolicy::default(),
+                InteractiveVisibility::default(),
+                InteractiveZIndex::default(),
+                InteractiveImage::default(),
+                InteractiveImageScaleMode::default(),
+            ))
+            .add_plugins((
+                ReflectPathInteractionPlugin::<f32>::default(),
+                ReflectPathInteractionPlugin::<Val>::default(),
+                ReflectPathInteractionPlugin::<UiRect>::default(),
+                ReflectPathInteractionPlugin::<Color>::default(),
+                ReflectPathInteractionPlugin::<Vec2>::default(),
+                ReflectPathInteractionPlugin::<Vec3>::default(),
+                ReflectPathInteractionPlugin::<Quat>::default(),
+            ));
     }
 }
 
@@ -33,6 +95,305 @@ pub struct InteractiveMargin;
 #[simple_interaction_for((Style, Val, "height"))]
 pub struct InteractiveHeight;
 
+#[simple_interaction_for((Style, Display, "display"))]
+pub struct InteractiveDisplay;
+
+#[simple_interaction_for((Style, PositionType, "position_type"))]
+pub struct InteractivePositionType;
+
+#[simple_interaction_for((Style, Overflow, "overflow"))]
+pub struct InteractiveOverflow;
+
+#[simple_interaction_for((Style, Direction, "direction"))]
+pub struct InteractiveDirection;
+
+#[simple_interaction_for((Style, Val, "left"))]
+pub struct InteractiveLeft;
+
+#[simple_interaction_for((Style, Val, "right"))]
+pub struct InteractiveRight;
+
+#[simple_interaction_for((Style, Val, "top"))]
+pub struct InteractiveTop;
+
+#[simple_interaction_for((Style, Val, "bottom"))]
+pub struct InteractiveBottom;
+
+#[simple_interaction_for((Style, Val, "width"))]
+pub struct InteractiveWidth;
+
+#[simple_interaction_for((Style, Val, "min_width"))]
+pub struct InteractiveMinWidth;
+
+#[simple_interaction_for((Style, Val, "min_height"))]
+pub struct InteractiveMinHeight;
+
+#[simple_interaction_for((Style, Option<f32>, "aspect_ratio"))]
+pub struct InteractiveAspectRatio;
+
+#[simple_interaction_for((Style, AlignItems, "align_items"))]
+pub struct InteractiveAlignItems;
+
+#[simple_interaction_for((Style, JustifyItems, "justify_items"))]
+pub struct InteractiveJustifyItems;
+
+#[simple_interaction_for((Style, AlignSelf, "align_self"))]
+pub struct InteractiveAlignSelf;
+
+#[simple_interaction_for((Style, JustifySelf, "justify_self"))]
+pub struct InteractiveJustifySelf;
+
+#[simple_interaction_for((Style, AlignContent, "align_content"))]
+pub struct InteractiveAlignContent;
+
+#[simple_interaction_for((Style, JustifyContent, "justify_content"))]
+pub struct InteractiveJustifyContent;
+
+#[simple_interaction_for((Style, UiRect, "padding"))]
+pub struct InteractivePadding;
+
+#[simple_interaction_for((Style, FlexDirection, "flex_direction"))]
+pub struct InteractiveFlexDirection;
+
+#[simple_interaction_for((Style, FlexWrap, "flex_wrap"))]
+pub struct InteractiveFlexWrap;
+
+#[simple_interaction_for((Style, f32, "flex_grow"))]
+pub struct InteractiveFlexGrow;
+
+#[simple_interaction_for((Style, f32, "flex_shrink"))]
+pub struct InteractiveFlexShrink;
+
+#[simple_interaction_for((Style, Val, "flex_basis"))]
+pub struct InteractiveFlexBasis;
+
+#[simple_interaction_for((Style, Val, "row_gap"))]
+pub struct InteractiveRowGap;
+
+#[simple_interaction_for((Style, Val, "column_gap"))]
+pub struct InteractiveColumnGap;
+
+#[simple_interaction_for((Style, GridAutoFlow, "grid_auto_flow"))]
+pub struct InteractiveGridAutoFlow;
+
+#[simple_interaction_for((Style, Vec<RepeatedGridTrack>, "grid_template_rows"))]
+pub struct InteractiveGridTemplateRows;
+
+#[simple_interaction_for((Style, Vec<RepeatedGridTrack>, "grid_template_columns"))]
+pub struct InteractiveGridTemplateColumns;
+
+#[simple_interaction_for((Style, Vec<GridTrack>, "grid_auto_rows"))]
+pub struct InteractiveGridAutoRows;
+
+#[simple_interaction_for((Style, Vec<GridTrack>, "grid_auto_columns"))]
+pub struct InteractiveGridAutoColumns;
+
+#[simple_interaction_for((Style, GridPlacement, "grid_row"))]
+pub struct InteractiveGridRow;
+
+#[simple_interaction_for((Style, GridPlacement, "grid_column"))]
+pub struct InteractiveGridColumn;
+
+#[simple_interaction_for((FocusPolicy, FocusPolicy))]
+pub struct InteractiveFocusPolicy;
+
+#[simple_interaction_for((Visibility, Visibility))]
+pub struct InteractiveVisibility;
+
+#[simple_interaction_for((ZIndex, ZIndex))]
+pub struct InteractiveZIndex;
+
+#[simple_interaction_for((UiImage, Handle<Image>, "texture"))]
+pub struct InteractiveImage;
+
+#[simple_interaction_for((ImageScaleMode, ImageScaleMode))]
+pub struct InteractiveImageScaleMode;
+
+/// Widget states that are not derived from the pointer (i.e. a checked checkbox).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Reflect)]
+pub enum PseudoState {
+    Focused,
+    Selected,
+    Checked,
+    Active,
+    Disabled,
+}
+
+/// The [`PseudoState`]s currently set on an entity. Widgets keep this up to date.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct PseudoStates(Vec<PseudoState>);
+
+impl PseudoStates {
+    pub fn new(states: Vec<PseudoState>) -> Self {
+        Self(states)
+    }
+
+    pub fn has(&self, state: PseudoState) -> bool {
+        self.0.contains(&state)
+    }
+
+    pub fn add(&mut self, state: PseudoState) {
+        if !self.has(state) {
+            self.0.push(state);
+        }
+    }
+
+    pub fn remove(&mut self, state: PseudoState) {
+        self.0.retain(|s| *s != state);
+    }
+}
+
+/// Updates a single [`PseudoState`], only triggering change detection if it actually changed.
+pub fn set_pseudo_state(pseudo_states: &mut Mut<PseudoStates>, state: PseudoState, value: bool) {
+    if pseudo_states.has(state) != value {
+        match value {
+            true => pseudo_states.add(state),
+            false => pseudo_states.remove(state),
+        }
+    }
+}
+
+/// The state an interactive value is resolved for, in ascending order of precedence.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Reflect)]
+pub enum StyleState {
+    #[default]
+    Default,
+    Checked,
+    Selected,
+    Active,
+    Hover,
+    Focused,
+    Pressed,
+    Disabled,
+}
+
+impl StyleState {
+    /// All non-default states, highest precedence first.
+    const PRECEDENCE: [StyleState; 7] = [
+        StyleState::Disabled,
+        StyleState::Pressed,
+        StyleState::Focused,
+        StyleState::Hover,
+        StyleState::Active,
+        StyleState::Selected,
+        StyleState::Checked,
+    ];
+
+    fn is_active(
+        self,
+        flux_interaction: &FluxInteraction,
+        pseudo_states: Option<&PseudoStates>,
+    ) -> bool {
+        let has_pseudo_state =
+            |state: PseudoState| pseudo_states.is_some_and(|states| states.has(state));
+
+        match self {
+            StyleState::Default => true,
+            StyleState::Checked => has_pseudo_state(PseudoState::Checked),
+            StyleState::Selected => has_pseudo_state(PseudoState::Selected),
+            StyleState::Active => has_pseudo_state(PseudoState::Active),
+            StyleState::Hover => matches!(
+                *flux_interaction,
+                FluxInteraction::PointerEnter
+                    | FluxInteraction::Pressed
+                    | FluxInteraction::Released
+            ),
+            StyleState::Focused => has_pseudo_state(PseudoState::Focused),
+            StyleState::Pressed => *flux_interaction == FluxInteraction::Pressed,
+            StyleState::Disabled => {
+                *flux_interaction == FluxInteraction::Disabled
+                    || has_pseudo_state(PseudoState::Disabled)
+            }
+        }
+    }
+}
+
+/// Seconds to wait before a state takes effect and before it is left.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect, Deserialize)]
+#[serde(default)]
+pub struct StateDelay {
+    pub enter: f32,
+    pub exit: f32,
+}
+
+/// Per state delays of an interactive value, i.e. a hover style that only applies
+/// after hovering for a while or a pressed style that lingers after release.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect, Deserialize)]
+#[serde(default)]
+pub struct StateDelays {
+    pub hover: StateDelay,
+    pub pressed: StateDelay,
+    pub focused: StateDelay,
+    pub selected: StateDelay,
+    pub checked: StateDelay,
+    pub active: StateDelay,
+    pub disabled: StateDelay,
+}
+
+impl StateDelays {
+    pub fn get(&self, state: StyleState) -> StateDelay {
+        match state {
+            StyleState::Default => StateDelay::default(),
+            StyleState::Checked => self.checked,
+            StyleState::Selected => self.selected,
+            StyleState::Active => self.active,
+            StyleState::Hover => self.hover,
+            StyleState::Focused => self.focused,
+            StyleState::Pressed => self.pressed,
+            StyleState::Disabled => self.disabled,
+        }
+    }
+
+    pub fn set(&mut self, state: StyleState, delay: StateDelay) {
+        match state {
+            StyleState::Default => (),
+            StyleState::Checked => self.checked = delay,
+            StyleState::Selected => self.selected = delay,
+            StyleState::Active => self.active = delay,
+            StyleState::Hover => self.hover = delay,
+            StyleState::Focused => self.focused = delay,
+            StyleState::Pressed => self.pressed = delay,
+            StyleState::Disabled => self.disabled = delay,
+        }
+    }
+
+    /// Delay of switching between two states.
+    ///
+    /// Entering a higher precedence state waits for its enter delay,
+    /// falling back to a lower one waits for the exit delay of the state left.
+    pub fn transition_delay(&self, from: StyleState, to: StyleState) -> f32 {
+        let delay = match to > from {
+            true => self.get(to).enter,
+            false => self.get(from).exit,
+        };
+
+        delay.max(0.)
+    }
+}
+
+/// A resolved style state waiting for its delay to pass
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PendingStyleState {
+    pub style_state: StyleState,
+    pub elapsed: f32,
+}
+
+/// Picks the highest precedence active state the controller has a value for.
+pub fn resolve_style_state<T: InteractionConfig>(
+    interaction_config: &T,
+    flux_interaction: &FluxInteraction,
+    pseudo_states: Option<&PseudoStates>,
+) -> StyleState {
+    StyleState::PRECEDENCE
+        .into_iter()
+        .find(|state| {
+            state.is_active(flux_interaction, pseudo_states)
+                && interaction_config.state_value(*state).is_some()
+        })
+        .unwrap_or_default()
+}
+
 pub trait InteractionConfig {
     type TargetType;
     fn new(
@@ -43,6 +404,14 @@ pub trait InteractionConfig {
     fn highlight(&self) -> Option<Self::TargetType>;
     fn pressed(&self) -> Option<Self::TargetType>;
     fn cancel(&self) -> Option<Self::TargetType>;
+    /// The value for `state`. Always `None` for [`StyleState::Default`].
+    fn state_value(&self, state: StyleState) -> Option<Self::TargetType>;
+    fn set_state_value(&mut self, state: StyleState, value: Option<Self::TargetType>);
+    fn delays(&self) -> StateDelays;
+    fn set_delays(&mut self, delays: StateDelays);
+    /// The value the idle animation plays towards, see [`AnimatedInteraction::idle`].
+    fn idle(&self) -> Option<Self::TargetType>;
+    fn set_idle(&mut self, value: Option<Self::TargetType>);
 }
 
 pub trait InteractionState {
@@ -50,8 +419,12 @@ pub trait InteractionState {
 
     fn original(&self) -> Self::TargetType;
     fn transition_base(&self) -> Self::TargetType;
+    fn style_state(&self) -> StyleState;
+    fn pending_style_state(&self) -> Option<PendingStyleState>;
     fn set_original(&mut self, from: Self::TargetType);
     fn set_transition_base(&mut self, from: Self::TargetType);
+    fn set_style_state(&mut self, state: StyleState);
+    fn set_pending_style_state(&mut self, pending: Option<PendingStyleState>);
 }
 
 pub trait ComponentController {
@@ -85,34 +458,14 @@ pub fn add_interactive_state<Interaction, State, ControlledComponent>(
     }
 }
 
-pub fn update_transition_base_state<Interaction, State, ControlledComponent, TargetType>(
-    mut q_interaction: Query<
-        (&ControlledComponent, &mut State, &FluxInteraction),
-        Changed<FluxInteraction>,
-    >,
-) where
-    State: Component + InteractionState<TargetType = TargetType>,
-    Interaction: ComponentController<
-        InteractionState = State,
-        TargetType = TargetType,
-        ControlledComponent = ControlledComponent,
-    >,
-    ControlledComponent: Component,
-    TargetType: Lerp,
-{
-    for (controlled_component, mut state, interaction) in &mut q_interaction {
-        if *interaction == FluxInteraction::Pressed {
-            state.set_transition_base(Interaction::extract_value(controlled_component));
-        }
-    }
-}
-
 pub fn update_controlled_component<Interaction, State, ControlledComponent, TransitionType>(
+    time: Res<Time<Real>>,
     mut q_interaction: Query<(
         &Interaction,
-        &State,
+        &mut State,
         &FluxInteraction,
-        Option<&AnimatedInteractionState<Interaction>>,
+        Option<&PseudoStates>,
+        Option<&mut AnimatedInteractionState<Interaction>>,
         &mut ControlledComponent,
     )>,
 ) where
@@ -131,76 +484,157 @@ pub fn update_controlled_component<Interaction, State, ControlledComponent, Tran
 {
     for (
         interaction_config,
-        transient_state,
+        mut transient_state,
         flux_interaction,
+        pseudo_states,
         animation_state,
         controlled_component,
     ) in &mut q_interaction
     {
-        if let Some(new_value) = calculate_interaction_result(
+        let new_value = interaction_value(
             interaction_config,
-            transient_state,
+            transient_state.as_mut(),
             flux_interaction,
-            animation_state,
-        ) {
-            Interaction::update_controlled_component(controlled_component, new_value);
-        }
+            pseudo_states,
+            animation_state.map(Mut::into_inner),
+            || Interaction::extract_value(&controlled_component),
+            time.delta_seconds(),
+        );
+        Interaction::update_controlled_component(controlled_component, new_value);
     }
 }
 
-pub fn calculate_interaction_result<T, S, R>(
+/// Advances the style state of an interactive value and returns the value to display.
+///
+/// `current_value` is only read when a transition starts, as its base.
+pub(crate) fn interaction_value<T, A, S, R>(
     interaction_config: &T,
-    transient_state: &S,
+    transient_state: &mut S,
     flux_interaction: &FluxInteraction,
-    animation_state: Option<&AnimatedInteractionState<T>>,
-) -> Option<R>
+    pseudo_states: Option<&PseudoStates>,
+    mut animation_state: Option<&mut AnimatedInteractionState<A>>,
+    current_value: impl FnOnce() -> R,
+    delta: f32,
+) -> R
 where
-    T: Component + Default + Reflect + InteractionConfig<TargetType = R>,
+    T: InteractionConfig<TargetType = R>,
+    A: Component + Default + Reflect,
     S: InteractionState<TargetType = R>,
     R: Lerp,
 {
-    let original_value = transient_state.original();
+    let resolved_state = resolve_style_state(interaction_config, flux_interaction, pseudo_states);
+    let prev_style_state = transient_state.style_state();
+    let style_state = delayed_style_state(
+        interaction_config.delays(),
+        transient_state,
+        resolved_state,
+        delta,
+    );
 
-    let (start_value, end_value) = match *flux_interaction {
-        FluxInteraction::Pressed => {
-            let Some(pressed_value) = interaction_config.pressed() else {
-                return None;
-            };
+    if style_state != prev_style_state {
+        // Leaving the pressed state by releasing outside the node animates from the cancel value
+        let canceled = prev_style_state == StyleState::Pressed
+            && *flux_interaction == FluxInteraction::PressCanceled;
+        let transition_base = match interaction_config.cancel() {
+            Some(cancel_value) if canceled => cancel_value,
+            _ => current_value(),
+        };
 
-            (transient_state.transition_base().into(), pressed_value)
-        }
-        FluxInteraction::Released => {
-            let end_value = interaction_config.highlight().unwrap_or(original_value);
+        transient_state.set_transition_base(transition_base);
+        transient_state.set_style_state(style_state);
 
-            (interaction_config.pressed(), end_value)
+        if let Some(animation_state) = animation_state.as_mut() {
+            animation_state.start_transition(prev_style_state, style_state, canceled);
         }
-        FluxInteraction::PressCanceled => (interaction_config.cancel(), original_value),
-        FluxInteraction::PointerEnter => {
-            let Some(highlight_value) = interaction_config.highlight() else {
-                return None;
-            };
+    }
 
-            (original_value.into(), highlight_value)
-        }
-        FluxInteraction::PointerLeave => {
-            let Some(highlight_color) = interaction_config.highlight() else {
-                return None;
-            };
+    calculate_interaction_result(
+        interaction_config,
+        transient_state,
+        animation_state.as_deref(),
+    )
+}
 
-            (highlight_color.into(), original_value)
+/// Returns the style state to use, keeping the current one until the resolved state's delay passed
+fn delayed_style_state<S: InteractionState>(
+    delays: StateDelays,
+    transient_state: &mut S,
+    resolved_state: StyleState,
+    delta: f32,
+) -> StyleState {
+    let current_state = transient_state.style_state();
+    if resolved_state == current_state {
+        if transient_state.pending_style_state().is_some() {
+            transient_state.set_pending_style_state(None);
         }
-        _ => (None, original_value),
+
+        return current_state;
+    }
+
+    let elapsed = match transient_state.pending_style_state() {
+        Some(pending) if pending.style_state == resolved_state => pending.elapsed + delta,
+        // A newly resolved state restarts the wait
+        _ => 0.,
     };
 
-    let new_value = if let (Some(state), Some(start_value)) = (animation_state, start_value) {
-        match state.progress {
-            AnimationProgress::Start => start_value,
-            AnimationProgress::Inbetween(tween_ratio) => start_value.lerp(end_value, tween_ratio),
-            AnimationProgress::End => end_value,
+    if elapsed >= delays.transition_delay(current_state, resolved_state) {
+        transient_state.set_pending_style_state(None);
+        return resolved_state;
+    }
+
+    transient_state.set_pending_style_state(
+        PendingStyleState {
+            style_state: resolved_state,
+            elapsed,
         }
-    } else {
-        end_value
+        .into(),
+    );
+
+    current_state
+}
+
+pub fn calculate_interaction_result<T, A, S, R>(
+    interaction_config: &T,
+    transient_state: &S,
+    animation_state: Option<&AnimatedInteractionState<A>>,
+) -> R
+where
+    T: InteractionConfig<TargetType = R>,
+    A: Component + Default + Reflect,
+    S: InteractionState<TargetType = R>,
+    R: Lerp,
+{
+    let end_value = interaction_config
+        .state_value(transient_state.style_state())
+        .unwrap_or(transient_state.original());
+
+    let Some(animation_state) = animation_state else {
+        return end_value;
     };
 
-    new_value.into()
+    if transient_state.style_state() == StyleState::Default {
+        if let (Some(idle_value), Some(idle_progress)) =
+            (interaction_config.idle(), animation_state.idle_progress)
+        {
+            return match idle_progress {
+                AnimationProgress::Start => transient_state.original(),
+                AnimationProgress::Inbetween(tween_ratio) => transient_state.original().lerp_in(
+                    idle_value,
+                    tween_ratio,
+                    animation_state.color_space,
+                ),
+                AnimationProgress::End => idle_value,
+            };
+        }
+    }
+
+    match animation_state.progress {
+        AnimationProgress::Start => transient_state.transition_base(),
+        AnimationProgress::Inbetween(tween_ratio) => transient_state.transition_base().lerp_in(
+            end_value,
+            tween_ratio,
+            animation_state.color_space,
+        ),
+        AnimationProgress::End => end_value,
+    }
 }
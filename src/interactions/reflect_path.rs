@@ -0,0 +1,466 @@
+use std::marker::PhantomData;
+
+use bevy::{
+    ecs::world::EntityWorldMut,
+    prelude::*,
+    reflect::{GetPath, GetTypeRegistration, ParsedPath, TypePath, TypeRegistry},
+};
+use sickle_math::lerp::Lerp;
+
+use crate::{
+    animated_interaction::{
+        AnimatedInteraction, AnimatedInteractionState, AnimatedInteractionUpdate,
+    },
+    FluxInteraction,
+};
+
+use super::{
+    interaction_value, InteractionConfig, InteractionState, PendingStyleState, PseudoStates,
+    StateDelays, StyleState,
+};
+
+/// Values an [`InteractiveReflectPath`] can drive
+pub trait ReflectPathValue: Lerp + Reflect + FromReflect + TypePath + Clone {}
+
+impl<T: Lerp + Reflect + FromReflect + TypePath + Clone> ReflectPathValue for T {}
+
+/// Adds [`InteractiveReflectPath`] support for values of type `V`.
+///
+/// [`super::InteractionsPlugin`] adds it for `f32`, `Val`, `UiRect`, `Color`, `Vec2`, `Vec3` and `Quat`.
+pub struct ReflectPathInteractionPlugin<V: ReflectPathValue>(PhantomData<V>);
+
+impl<V: ReflectPathValue> Default for ReflectPathInteractionPlugin<V> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<V: ReflectPathValue + GetTypeRegistration> Plugin for ReflectPathInteractionPlugin<V> {
+    fn build(&self, app: &mut App) {
+        app.register_type::<InteractiveReflectPath<V>>()
+            .register_type::<ReflectPathConfig<V>>()
+            .add_systems(
+                Update,
+                update_reflect_path_interaction::<V>.after(AnimatedInteractionUpdate),
+            );
+    }
+}
+
+/// Interactive controller for any fields of reflected components, addressed by path.
+///
+/// Each path starts with the component's (short) type path, followed by the field path,
+/// i.e. `Style.width`, `Transform.scale`, `UiImage.color` or `Text.sections[0].style.color`.
+/// Animate them with an `AnimatedInteraction<InteractiveReflectPath<V>>`, every path plays
+/// its own transitions.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct InteractiveReflectPath<V: ReflectPathValue> {
+    pub paths: Vec<ReflectPathConfig<V>>,
+}
+
+impl<V: ReflectPathValue> Default for InteractiveReflectPath<V> {
+    fn default() -> Self {
+        Self {
+            paths: Default::default(),
+        }
+    }
+}
+
+impl<V: ReflectPathValue> InteractiveReflectPath<V> {
+    pub fn from_vec(paths: Vec<ReflectPathConfig<V>>) -> Self {
+        Self { paths }
+    }
+}
+
+/// The interaction values of a single path of an [`InteractiveReflectPath`]
+#[derive(Clone, Debug, Reflect)]
+pub struct ReflectPathConfig<V: ReflectPathValue> {
+    pub path: String,
+    pub highlight: Option<V>,
+    pub pressed: Option<V>,
+    pub cancel: Option<V>,
+    pub focused: Option<V>,
+    pub selected: Option<V>,
+    pub checked: Option<V>,
+    pub active: Option<V>,
+    pub disabled: Option<V>,
+    pub idle: Option<V>,
+    pub delays: StateDelays,
+}
+
+impl<V: ReflectPathValue> Default for ReflectPathConfig<V> {
+    fn default() -> Self {
+        Self {
+            path: Default::default(),
+            highlight: None,
+            pressed: None,
+            cancel: None,
+            focused: None,
+            selected: None,
+            checked: None,
+            active: None,
+            disabled: None,
+            idle: None,
+            delays: Default::default(),
+        }
+    }
+}
+
+impl<V: ReflectPathValue> ReflectPathConfig<V> {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            ..default()
+        }
+    }
+}
+
+impl<V: ReflectPathValue> InteractionConfig for ReflectPathConfig<V> {
+    type TargetType = V;
+
+    fn new(
+        highlight: Option<Self::TargetType>,
+        pressed: Option<Self::TargetType>,
+        cancel: Option<Self::TargetType>,
+    ) -> Self {
+        Self {
+            highlight,
+            pressed,
+            cancel,
+            ..default()
+        }
+    }
+
+    fn highlight(&self) -> Option<Self::TargetType> {
+        self.highlight.clone()
+    }
+
+    fn pressed(&self) -> Option<Self::TargetType> {
+        self.pressed.clone()
+    }
+
+    fn cancel(&self) -> Option<Self::TargetType> {
+        self.cancel.clone()
+    }
+
+    fn state_value(&self, state: StyleState) -> Option<Self::TargetType> {
+        match state {
+            StyleState::Default => None,
+            StyleState::Checked => self.checked.clone(),
+            StyleState::Selected => self.selected.clone(),
+            StyleState::Active => self.active.clone(),
+            StyleState::Hover => self.highlight.clone(),
+            StyleState::Focused => self.focused.clone(),
+            StyleState::Pressed => self.pressed.clone(),
+            StyleState::Disabled => self.disabled.clone(),
+        }
+    }
+
+    fn set_state_value(&mut self, state: StyleState, value: Option<Self::TargetType>) {
+        match state {
+            StyleState::Default => (),
+            StyleState::Checked => self.checked = value,
+            StyleState::Selected => self.selected = value,
+            StyleState::Active => self.active = value,
+            StyleState::Hover => self.highlight = value,
+            StyleState::Focused => self.focused = value,
+            StyleState::Pressed => self.pressed = value,
+            StyleState::Disabled => self.disabled = value,
+        }
+    }
+
+    fn delays(&self) -> StateDelays {
+        self.delays
+    }
+
+    fn set_delays(&mut self, delays: StateDelays) {
+        self.delays = delays;
+    }
+
+    fn idle(&self) -> Option<Self::TargetType> {
+        self.idle.clone()
+    }
+
+    fn set_idle(&mut self, value: Option<Self::TargetType>) {
+        self.idle = value;
+    }
+}
+
+/// The component and field a path resolved to
+struct ReflectPathTarget {
+    reflect_component: ReflectComponent,
+    field_path: Option<ParsedPath>,
+}
+
+impl ReflectPathTarget {
+    fn resolve(path: &str, type_registry: &TypeRegistry) -> Result<Self, String> {
+        let index = path.find(['.', '[']).unwrap_or(path.len());
+        let (component_path, field_path) = path.split_at(index);
+        let field_path = field_path.strip_prefix('.').unwrap_or(field_path);
+
+        let Some(reflect_component) = type_registry
+            .get_with_short_type_path(component_path)
+            .or_else(|| type_registry.get_with_type_path(component_path))
+            .and_then(|registration| registration.data::<ReflectComponent>())
+        else {
+            return Err(format!("{} is not a registered component", component_path));
+        };
+
+        let field_path = match field_path.is_empty() {
+            true => None,
+            false => Some(ParsedPath::parse(field_path).map_err(|err| err.to_string())?),
+        };
+
+        Ok(Self {
+            reflect_component: reflect_component.clone(),
+            field_path,
+        })
+    }
+
+    /// Reads the field, `None` while the entity doesn't have the component
+    fn read<V: ReflectPathValue>(
+        &self,
+        entity: Entity,
+        world: &World,
+    ) -> Option<Result<V, String>> {
+        let component = self.reflect_component.reflect(world.entity(entity))?;
+        let field = match &self.field_path {
+            Some(field_path) => component
+                .reflect_path(field_path)
+                .map_err(|err| err.to_string()),
+            None => Ok(component),
+        };
+
+        Some(field.and_then(|field| {
+            V::from_reflect(field).ok_or(format!("Field is not a {}", V::type_path()))
+        }))
+    }
+
+    fn write<V: ReflectPathValue>(&self, entity_mut: &mut EntityWorldMut, value: &V) {
+        let Some(mut component) = self.reflect_component.reflect_mut(entity_mut) else {
+            return;
+        };
+
+        // Only trigger change detection if the value actually changed
+        let field = match &self.field_path {
+            Some(field_path) => component
+                .bypass_change_detection()
+                .reflect_path_mut(field_path),
+            None => Ok(component.bypass_change_detection()),
+        };
+        let Ok(field) = field else {
+            return;
+        };
+        if field.reflect_partial_eq(value) != Some(true) {
+            field.apply(value);
+            component.set_changed();
+        }
+    }
+}
+
+/// Resolved targets and interaction states of the paths of an [`InteractiveReflectPath`]
+#[derive(Component)]
+pub struct InteractiveReflectPathState<V: ReflectPathValue> {
+    paths: Vec<ReflectPathState<V>>,
+}
+
+struct ReflectPathState<V: ReflectPathValue> {
+    path: String,
+    target: Option<ReflectPathTarget>,
+    interaction: Option<ReflectPathInteractionState<V>>,
+    animation_state: AnimatedInteractionState<InteractiveReflectPath<V>>,
+}
+
+impl<V: ReflectPathValue> ReflectPathState<V> {
+    fn update(
+        &mut self,
+        entity: Entity,
+        index: usize,
+        world: &mut World,
+        delta: f32,
+        config_changed: bool,
+    ) {
+        let Some(target) = &self.target else {
+            return;
+        };
+
+        // The controlled component may be inserted later
+        let current_value = match target.read::<V>(entity, world) {
+            Some(Ok(value)) => value,
+            Some(Err(err)) => {
+                if config_changed {
+                    warn!(
+                        "Failed to drive {} of entity {:?}: {}",
+                        self.path, entity, err
+                    );
+                }
+                return;
+            }
+            None => return,
+        };
+
+        let Some(interaction) = self.interaction.as_mut() else {
+            self.interaction = ReflectPathInteractionState {
+                original: current_value.clone(),
+                transition_base: current_value,
+                style_state: StyleState::Default,
+                pending_style_state: None,
+            }
+            .into();
+            return;
+        };
+
+        let entity_ref = world.entity(entity);
+        let (Some(config), Some(flux_interaction)) = (
+            entity_ref
+                .get::<InteractiveReflectPath<V>>()
+                .and_then(|config| config.paths.get(index)),
+            entity_ref.get::<FluxInteraction>(),
+        ) else {
+            return;
+        };
+
+        let animation = entity_ref.get::<AnimatedInteraction<InteractiveReflectPath<V>>>();
+        if let Some(animation) = animation {
+            self.animation_state.update(animation, delta);
+        }
+
+        let new_value = interaction_value(
+            config,
+            interaction,
+            flux_interaction,
+            entity_ref.get::<PseudoStates>(),
+            animation.map(|_| &mut self.animation_state),
+            || current_value,
+            delta,
+        );
+
+        target.write(&mut world.entity_mut(entity), &new_value);
+    }
+}
+
+/// Interaction state of a single path, created once its field is first read
+struct ReflectPathInteractionState<V: ReflectPathValue> {
+    original: V,
+    transition_base: V,
+    style_state: StyleState,
+    pending_style_state: Option<PendingStyleState>,
+}
+
+impl<V: ReflectPathValue> InteractionState for ReflectPathInteractionState<V> {
+    type TargetType = V;
+
+    fn original(&self) -> Self::TargetType {
+        self.original.clone()
+    }
+    fn transition_base(&self) -> Self::TargetType {
+        self.transition_base.clone()
+    }
+    fn style_state(&self) -> StyleState {
+        self.style_state
+    }
+    fn pending_style_state(&self) -> Option<PendingStyleState> {
+        self.pending_style_state
+    }
+    fn set_original(&mut self, from: Self::TargetType) {
+        self.original = from;
+    }
+    fn set_transition_base(&mut self, from: Self::TargetType) {
+        self.transition_base = from;
+    }
+    fn set_style_state(&mut self, state: StyleState) {
+        self.style_state = state;
+    }
+    fn set_pending_style_state(&mut self, pending: Option<PendingStyleState>) {
+        self.pending_style_state = pending;
+    }
+}
+
+/// Resolves the paths of a new or changed [`InteractiveReflectPath`].
+/// Paths that stay keep their interaction state.
+fn resolve_reflect_paths<V: ReflectPathValue>(entity: Entity, world: &mut World) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let mut previous = world
+        .entity_mut(entity)
+        .take::<InteractiveReflectPathState<V>>()
+        .map(|state| state.paths)
+        .unwrap_or_default();
+
+    let Some(config) = world.get::<InteractiveReflectPath<V>>(entity) else {
+        return;
+    };
+
+    let paths = config
+        .paths
+        .iter()
+        .map(|path_config| {
+            if let Some(index) = previous
+                .iter()
+                .position(|state| state.path == path_config.path)
+            {
+                return previous.swap_remove(index);
+            }
+
+            let target = match ReflectPathTarget::resolve(&path_config.path, &type_registry) {
+                Ok(target) => target.into(),
+                Err(err) => {
+                    warn!(
+                        "Failed to drive {} of entity {:?}: {}",
+                        path_config.path, entity, err
+                    );
+                    None
+                }
+            };
+
+            ReflectPathState {
+                path: path_config.path.clone(),
+                target,
+                interaction: None,
+                animation_state: Default::default(),
+            }
+        })
+        .collect();
+
+    world
+        .entity_mut(entity)
+        .insert(InteractiveReflectPathState::<V> { paths });
+}
+
+fn update_reflect_path_interaction<V: ReflectPathValue>(world: &mut World) {
+    let delta = world.resource::<Time<Real>>().delta_seconds();
+    let entities: Vec<Entity> = world
+        .query_filtered::<Entity, (With<InteractiveReflectPath<V>>, With<FluxInteraction>)>()
+        .iter(world)
+        .collect();
+
+    for entity in entities {
+        let config = world.entity(entity).get_ref::<InteractiveReflectPath<V>>();
+        let Some(config) = config else {
+            continue;
+        };
+        let config_changed = config.is_changed();
+
+        if config_changed
+            || world
+                .get::<InteractiveReflectPathState<V>>(entity)
+                .is_none()
+        {
+            resolve_reflect_paths::<V>(entity, world);
+        }
+
+        let Some(mut state) = world.get_mut::<InteractiveReflectPathState<V>>(entity) else {
+            continue;
+        };
+
+        // Paths need the world to read and write the driven components
+        let mut paths = std::mem::take(&mut state.paths);
+        for (index, path) in paths.iter_mut().enumerate() {
+            path.update(entity, index, world, delta, config_changed);
+        }
+
+        if let Some(mut state) = world.get_mut::<InteractiveReflectPathState<V>>(entity) {
+            state.paths = paths;
+        }
+    }
+}
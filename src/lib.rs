@@ -6,13 +6,16 @@ pub mod dev_panels;
 pub mod drag_interaction;
 pub mod drop_interaction;
 pub mod flux_interaction;
+pub mod focus_interaction;
 pub mod hierarchy_delay;
 pub mod input_extension;
 pub mod interactions;
+pub mod node_window;
 pub mod resize_interaction;
 pub mod scroll_interaction;
 pub mod theme;
 pub mod ui_builder;
+pub mod ui_callbacks;
 pub mod ui_commands;
 pub mod ui_style;
 pub mod widgets;
@@ -21,10 +24,14 @@ use assets::BuiltInAssetsPlugin;
 use drag_interaction::DragInteractionPlugin;
 use drop_interaction::DropInteractionPlugin;
 pub use flux_interaction::*;
+use focus_interaction::FocusInteractionPlugin;
 use hierarchy_delay::HierarchyDelayPlugin;
 use interactions::InteractionsPlugin;
 use resize_interaction::ResizeHandlePlugin;
 use scroll_interaction::ScrollInteractionPlugin;
+use theme::ThemePlugin;
+use ui_callbacks::UiCallbacksPlugin;
+use ui_style::animation::StyleAnimationPlugin;
 use widgets::WidgetsPlugin;
 
 use self::animated_interaction::AnimatedInteractionPlugin;
@@ -43,9 +50,13 @@ impl Plugin for SickleUiPlugin {
             DropInteractionPlugin,
             HierarchyDelayPlugin,
             FluxInteractionPlugin,
+            FocusInteractionPlugin,
             InteractionsPlugin,
             ResizeHandlePlugin,
             ScrollInteractionPlugin,
+            StyleAnimationPlugin,
+            ThemePlugin,
+            UiCallbacksPlugin,
             WidgetsPlugin,
         ));
     }
@@ -0,0 +1,125 @@
+use bevy::{
+    ecs::system::SystemParam,
+    prelude::*,
+    render::{camera::RenderTarget, view::ViewVisibility},
+    ui::{CalculatedClip, DefaultUiCamera, FocusPolicy, TargetCamera, UiStack},
+    window::{PrimaryWindow, WindowRef},
+};
+
+/// Looks up the camera and window a UI node is rendered into.
+///
+/// Uses the [`TargetCamera`] of the node or its nearest ancestor, falling back to the
+/// default UI camera like `bevy_ui` does. Nodes rendered to a texture have no window.
+#[derive(SystemParam)]
+pub struct NodeWindow<'w, 's> {
+    q_target_camera: Query<'w, 's, &'static TargetCamera>,
+    q_parent: Query<'w, 's, &'static Parent>,
+    q_camera: Query<'w, 's, &'static Camera>,
+    q_primary_window: Query<'w, 's, Entity, With<PrimaryWindow>>,
+    default_ui_camera: DefaultUiCamera<'w, 's>,
+    ui_scale: Res<'w, UiScale>,
+}
+
+impl<'w, 's> NodeWindow<'w, 's> {
+    pub fn camera(&self, node: Entity) -> Option<Entity> {
+        // Descendants only receive the root's camera during PostUpdate
+        let target_camera = self
+            .q_target_camera
+            .get(node)
+            .ok()
+            .or_else(|| {
+                self.q_parent
+                    .iter_ancestors(node)
+                    .find_map(|ancestor| self.q_target_camera.get(ancestor).ok())
+            })
+            .map(TargetCamera::entity);
+
+        target_camera.or_else(|| self.default_ui_camera.get())
+    }
+
+    pub fn window(&self, node: Entity) -> Option<Entity> {
+        let camera = self.q_camera.get(self.camera(node)?).ok()?;
+
+        match camera.target {
+            RenderTarget::Window(WindowRef::Primary) => self.q_primary_window.get_single().ok(),
+            RenderTarget::Window(WindowRef::Entity(window)) => window.into(),
+            _ => None,
+        }
+    }
+
+    /// Converts a logical window position to the UI coordinates of the node's camera
+    pub fn ui_position(&self, node: Entity, position: Vec2) -> Option<Vec2> {
+        let camera = self.q_camera.get(self.camera(node)?).ok()?;
+        let viewport_position = camera
+            .logical_viewport_rect()
+            .map(|rect| rect.min)
+            .unwrap_or_default();
+
+        Some((position - viewport_position) / self.ui_scale.0)
+    }
+}
+
+/// Finds the UI nodes under a pointer of a given window.
+///
+/// Nodes are returned top-most first, stopping at the first node that blocks
+/// focus, the same way `bevy_ui` resolves [`Interaction`] for the main pointer.
+#[derive(SystemParam)]
+pub struct NodePicker<'w, 's> {
+    node_window: NodeWindow<'w, 's>,
+    ui_stack: Res<'w, UiStack>,
+    q_node: Query<
+        'w,
+        's,
+        (
+            &'static Node,
+            &'static GlobalTransform,
+            &'static ViewVisibility,
+        ),
+    >,
+    q_clip: Query<'w, 's, &'static CalculatedClip>,
+    q_focus_policy: Query<'w, 's, &'static FocusPolicy>,
+}
+
+impl<'w, 's> NodePicker<'w, 's> {
+    pub fn node_window(&self) -> &NodeWindow<'w, 's> {
+        &self.node_window
+    }
+
+    pub fn pick(&self, window: Entity, position: Vec2) -> Vec<Entity> {
+        let mut picked = Vec::new();
+
+        for entity in self.ui_stack.uinodes.iter().rev() {
+            let Ok((node, transform, visibility)) = self.q_node.get(*entity) else {
+                continue;
+            };
+
+            if !visibility.get() || self.node_window.window(*entity) != Some(window) {
+                continue;
+            }
+
+            let Some(position) = self.node_window.ui_position(*entity, position) else {
+                continue;
+            };
+
+            let node_rect = node.logical_rect(transform);
+            let visible_rect = self
+                .q_clip
+                .get(*entity)
+                .ok()
+                .map(|clip| node_rect.intersect(clip.clip))
+                .unwrap_or(node_rect);
+
+            if visible_rect.is_empty() || !visible_rect.contains(position) {
+                continue;
+            }
+
+            picked.push(*entity);
+            let focus_policy = self.q_focus_policy.get(*entity).ok();
+            if *focus_policy.unwrap_or(&FocusPolicy::Block) == FocusPolicy::Block {
+                break;
+            }
+        }
+
+        picked
+    }
+}
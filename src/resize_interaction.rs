@@ -6,6 +6,7 @@ use crate::{
     animated_interaction::{AnimatedInteraction, AnimationConfig},
     drag_interaction::Draggable,
     interactions::InteractiveBackground,
+    theme::tokens::{ColorToken, TokenStyle},
     ui_commands::SetCursorExt,
     FluxInteraction, FluxInteractionUpdate, TrackedInteraction,
 };
@@ -30,14 +31,14 @@ fn should_update_resize_handle_cursor(
 }
 
 fn update_cursor_on_resize_handles(
-    q_flux: Query<(&ResizeHandle, &FluxInteraction)>,
+    q_flux: Query<(Entity, &ResizeHandle, &FluxInteraction)>,
     mut locked: Local<bool>,
     mut commands: Commands,
 ) {
-    let mut new_cursor: Option<CursorIcon> = None;
+    let mut new_cursor: Option<(Entity, CursorIcon)> = None;
     let multiple_active = q_flux
         .iter()
-        .filter(|(_, flux)| {
+        .filter(|(_, _, flux)| {
             (**flux == FluxInteraction::PointerEnter && !*locked)
                 || **flux == FluxInteraction::Pressed
         })
@@ -46,46 +47,46 @@ fn update_cursor_on_resize_handles(
 
     let omni_cursor = CursorIcon::Move;
 
-    for (handle, flux) in &q_flux {
+    for (entity, handle, flux) in &q_flux {
         match *flux {
             FluxInteraction::PointerEnter => {
                 if !*locked {
                     new_cursor = match multiple_active {
-                        true => omni_cursor.into(),
-                        false => handle.direction.cursor().into(),
+                        true => (entity, omni_cursor).into(),
+                        false => (entity, handle.direction.cursor()).into(),
                     };
                 }
             }
             FluxInteraction::Pressed => {
                 new_cursor = match multiple_active {
-                    true => omni_cursor.into(),
-                    false => handle.direction.cursor().into(),
+                    true => (entity, omni_cursor).into(),
+                    false => (entity, handle.direction.cursor()).into(),
                 };
                 *locked = true;
             }
             FluxInteraction::Released => {
                 *locked = false;
                 if new_cursor.is_none() {
-                    new_cursor = CursorIcon::Default.into();
+                    new_cursor = (entity, CursorIcon::Default).into();
                 }
             }
             FluxInteraction::PressCanceled => {
                 *locked = false;
                 if new_cursor.is_none() {
-                    new_cursor = CursorIcon::Default.into();
+                    new_cursor = (entity, CursorIcon::Default).into();
                 }
             }
             FluxInteraction::PointerLeave => {
                 if !*locked && new_cursor.is_none() {
-                    new_cursor = CursorIcon::Default.into();
+                    new_cursor = (entity, CursorIcon::Default).into();
                 }
             }
             _ => (),
         }
     }
 
-    if let Some(new_cursor) = new_cursor {
-        commands.set_cursor(new_cursor);
+    if let Some((entity, new_cursor)) = new_cursor {
+        commands.entity(entity).set_cursor(new_cursor);
     }
 }
 
@@ -175,8 +176,9 @@ impl ResizeHandle {
             },
             Interaction::default(),
             TrackedInteraction::default(),
-            InteractiveBackground {
-                highlight: Color::rgb(0., 0.5, 1.).into(),
+            InteractiveBackground::default(),
+            TokenStyle {
+                highlight: ColorToken::Accent.into(),
                 ..default()
             },
             AnimatedInteraction::<InteractiveBackground> {
@@ -1,14 +1,298 @@
-use std::marker::PhantomData;
+pub mod tokens;
 
-use bevy::{prelude::*, utils::HashSet};
+use std::{collections::HashMap, marker::PhantomData};
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    ecs::{
+        entity::EntityHashSet,
+        system::{EntityCommand, SystemParam},
+    },
+    prelude::*,
+    ui::{FocusPolicy, UiSystem},
+    utils::{get_short_name, BoxedFuture, HashSet},
+};
+use serde::Deserialize;
 use sickle_math::lerp::Lerp;
+use thiserror::Error;
+
+use tokens::{apply_token_style, ThemeTokens, TokenStyle};
 
 use crate::{
-    animated_interaction::AnimatedInteraction,
-    interactions::{ComponentController, InteractiveBackground},
+    animated_interaction::{AnimatedInteraction, AnimatedInteractionState},
+    interactions::*,
+    ui_style::{SetFluxInteractionUncheckedExt, UiStyleUncheckedExt},
+    FluxInteraction, TrackedInteraction,
 };
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ThemePlugin;
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<ThemeAsset>()
+            .init_asset_loader::<ThemeAssetLoader>()
+            .init_resource::<ThemeTokens>()
+            .register_type::<ThemeTokens>()
+            .register_type::<TokenStyle>()
+            .configure_sets(PostUpdate, ThemeUpdate.before(UiSystem::Layout))
+            .add_systems(PostUpdate, apply_token_style.in_set(ThemeUpdate));
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct ThemeUpdate;
+
+/// Applies the nearest [`Theme<C>`] to entities with component `C`.
+///
+/// The theme is looked up on the entity itself, then up its ancestors. It is applied
+/// when `C` is added and re-applied whenever the resolved [`Theme<C>`] or the
+/// [`ThemeTokens`] change, so themes take precedence over token styles. Removing a
+/// [`Theme<C>`] or moving the entity to another parent applies the new nearest theme,
+/// attributes the new theme doesn't style (or all, without one) lose their interactions
+/// and animations.
+///
+/// Entities holding a `Handle<ThemeAsset>` get their [`Theme<C>`] (re)built from the
+/// asset entry named after `C` each time the asset loads or is modified. A removed entry
+/// removes the [`Theme<C>`], attributes no longer set lose their interactions and animations.
+pub struct ComponentThemePlugin<C>
+where
+    C: Component,
+{
+    context: PhantomData<C>,
+}
+
+impl<C> Default for ComponentThemePlugin<C>
+where
+    C: Component,
+{
+    fn default() -> Self {
+        Self {
+            context: Default::default(),
+        }
+    }
+}
+
+impl<C> Plugin for ComponentThemePlugin<C>
+where
+    C: Component,
+{
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (update_theme_from_asset::<C>, apply_theme::<C>)
+                .chain()
+                .after(apply_token_style)
+                .in_set(ThemeUpdate),
+        );
+    }
+}
+
+fn update_theme_from_asset<C>(
+    mut asset_events: EventReader<AssetEvent<ThemeAsset>>,
+    q_sources: Query<(Entity, Ref<Handle<ThemeAsset>>)>,
+    theme_assets: Res<Assets<ThemeAsset>>,
+    themed: ThemedTargets<C>,
+    mut commands: Commands,
+) where
+    C: Component,
+{
+    let changed_assets: Vec<AssetId<ThemeAsset>> = asset_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    for (entity, handle) in &q_sources {
+        if !handle.is_changed() && !changed_assets.contains(&handle.id()) {
+            continue;
+        }
+
+        let Some(theme_asset) = theme_assets.get(handle.id()) else {
+            continue;
+        };
+
+        let style = theme_asset.style_for::<C>();
+        if let Ok(theme) = themed.q_themes.get(entity) {
+            let dropped: Vec<&StyledAttribute> = theme
+                .style
+                .iter()
+                .filter(|attribute| !style.is_some_and(|style| style.contains(attribute)))
+                .collect();
+
+            if !dropped.is_empty() {
+                for target in themed.themed_by(entity) {
+                    for attribute in dropped.iter() {
+                        attribute.clear(target, &mut commands);
+                    }
+                }
+            }
+        }
+
+        match style {
+            Some(style) => {
+                commands
+                    .entity(entity)
+                    .insert(Theme::<C>::new(style.clone()));
+            }
+            None => {
+                commands.entity(entity).remove::<Theme<C>>();
+            }
+        }
+    }
+}
+
+#[derive(SystemParam)]
+struct ThemedTargets<'w, 's, C>
+where
+    C: Component,
+{
+    q_themes: Query<'w, 's, &'static Theme<C>>,
+    q_targets: Query<'w, 's, Entity, With<C>>,
+    q_parent: Query<'w, 's, &'static Parent>,
+    q_children: Query<'w, 's, &'static Children>,
+}
+
+impl<'w, 's, C> ThemedTargets<'w, 's, C>
+where
+    C: Component,
+{
+    /// Targets whose nearest [`Theme<C>`] is on `source`
+    fn themed_by(&self, source: Entity) -> Vec<Entity> {
+        std::iter::once(source)
+            .chain(self.q_children.iter_descendants(source))
+            .filter(|entity| self.q_targets.contains(*entity))
+            .filter(|entity| {
+                std::iter::once(*entity)
+                    .chain(self.q_parent.iter_ancestors(*entity))
+                    .find(|candidate| self.q_themes.contains(*candidate))
+                    == Some(source)
+            })
+            .collect()
+    }
+}
+
+/// Entities whose nearest [`Theme<C>`] may have changed since the last frame
+#[derive(SystemParam)]
+struct ThemeChanges<'w, 's, C>
+where
+    C: Component,
+{
+    q_added_targets: Query<'w, 's, Entity, Added<C>>,
+    q_changed_themes: Query<'w, 's, Entity, Changed<Theme<C>>>,
+    removed_themes: RemovedComponents<'w, 's, Theme<C>>,
+    q_reparented: Query<'w, 's, Entity, Changed<Parent>>,
+    removed_parents: RemovedComponents<'w, 's, Parent>,
+    tokens: Res<'w, ThemeTokens>,
+}
+
+/// Attributes the nearest [`Theme<C>`] styled on the entity, so they can be cleared once
+/// the entity resolves to a theme without them
+#[derive(Component)]
+struct AppliedThemeStyle<C>
+where
+    C: Component,
+{
+    style: Vec<StyledAttribute>,
+    context: PhantomData<C>,
+}
+
+fn apply_theme<C>(
+    q_targets: Query<(Entity, Option<&LockedStyleAttributes>), With<C>>,
+    q_applied: Query<&AppliedThemeStyle<C>>,
+    q_themes: Query<&Theme<C>>,
+    q_parent: Query<&Parent>,
+    q_children: Query<&Children>,
+    mut changes: ThemeChanges<C>,
+    mut commands: Commands,
+) where
+    C: Component,
+{
+    let changed_themes: EntityHashSet = changes.q_changed_themes.iter().collect();
+
+    // Targets under a changed theme only re-apply if it is still their nearest one,
+    // targets under a removed theme or a moved branch re-resolve unconditionally
+    let mut theme_targets: EntityHashSet = EntityHashSet::default();
+    let mut moved_targets: EntityHashSet = EntityHashSet::default();
+    if changes.tokens.is_changed() {
+        moved_targets.extend(q_targets.iter().map(|(entity, _)| entity));
+    } else {
+        moved_targets.extend(changes.q_added_targets.iter());
+
+        for root in changed_themes.iter() {
+            theme_targets.extend(
+                std::iter::once(*root)
+                    .chain(q_children.iter_descendants(*root))
+                    .filter(|entity| q_targets.contains(*entity)),
+            );
+        }
+
+        let moved_roots: Vec<Entity> = changes
+            .removed_themes
+            .read()
+            .chain(changes.removed_parents.read())
+            .chain(changes.q_reparented.iter())
+            .collect();
+        for root in moved_roots {
+            moved_targets.extend(
+                std::iter::once(root)
+                    .chain(q_children.iter_descendants(root))
+                    .filter(|entity| q_targets.contains(*entity)),
+            );
+        }
+    }
+
+    for entity in moved_targets.union(&theme_targets) {
+        let Ok((entity, locked_attrs)) = q_targets.get(*entity) else {
+            continue;
+        };
+        let applied = q_applied.get(entity).ok();
+
+        let theme_source = std::iter::once(entity)
+            .chain(q_parent.iter_ancestors(entity))
+            .find(|candidate| q_themes.contains(*candidate));
+
+        if let Some(theme_source) = theme_source {
+            if !moved_targets.contains(&entity) && !changed_themes.contains(&theme_source) {
+                continue;
+            }
+        }
+
+        // Safe unwrap: the source was found by checking the same query
+        let theme = theme_source.map(|source| q_themes.get(source).unwrap());
+        if let Some(applied) = applied {
+            for attribute in applied.style.iter() {
+                if !theme.is_some_and(|theme| theme.style.contains(attribute)) {
+                    attribute.clear(entity, &mut commands);
+                }
+            }
+        }
+
+        let Some(theme) = theme else {
+            if applied.is_some() {
+                commands.entity(entity).remove::<AppliedThemeStyle<C>>();
+            }
+            continue;
+        };
+
+        commands.entity(entity).insert(AppliedThemeStyle::<C> {
+            style: theme.style.clone(),
+            context: PhantomData,
+        });
+        for attribute in theme.style.iter() {
+            if let (Some(locked_attrs), Some(stylable)) = (locked_attrs, attribute.attribute()) {
+                if locked_attrs.contains(stylable) {
+                    continue;
+                }
+            }
+
+            attribute.apply(entity, &mut commands);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum StylableAttribute {
     Display,
     PositionType,
@@ -59,46 +343,318 @@ pub enum StylableAttribute {
     FluxInteraction,
 }
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default, Deserialize)]
 pub struct AttributeStateStyle<T> {
-    _default: T,
-    _hover: Option<T>,
-    _pressed: Option<T>,
-    _focused: Option<T>,
-    _selected: Option<T>,
-    _checked: Option<T>,
-    _active: Option<T>,
-    _disabled: Option<T>,
+    pub default: T,
+    pub hover: Option<T>,
+    pub pressed: Option<T>,
+    pub focused: Option<T>,
+    pub selected: Option<T>,
+    pub checked: Option<T>,
+    pub active: Option<T>,
+    pub disabled: Option<T>,
+    /// Only used by animated styles with an idle animation
+    pub idle: Option<T>,
+    #[serde(default)]
+    pub delays: StateDelays,
 }
 
-#[derive(Debug)]
+impl<T> AttributeStateStyle<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            default,
+            hover: None,
+            pressed: None,
+            focused: None,
+            selected: None,
+            checked: None,
+            active: None,
+            disabled: None,
+            idle: None,
+            delays: StateDelays::default(),
+        }
+    }
+
+    fn controller<C>(self) -> C
+    where
+        C: InteractionConfig<TargetType = T>,
+    {
+        let mut controller = C::new(self.hover, self.pressed, None);
+        controller.set_state_value(StyleState::Focused, self.focused);
+        controller.set_state_value(StyleState::Selected, self.selected);
+        controller.set_state_value(StyleState::Checked, self.checked);
+        controller.set_state_value(StyleState::Active, self.active);
+        controller.set_state_value(StyleState::Disabled, self.disabled);
+        controller.set_idle(self.idle);
+        controller.set_delays(self.delays);
+
+        controller
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
 pub enum AttributeStyle<T: Lerp, C: Component + ComponentController<TargetType = T>> {
     Static(T),
     PerState(AttributeStateStyle<T>),
-    // TODO: Handle delay: should be on the status change level to also apply to PerState
-    Animated(AnimatedInteraction<C>),
+    Animated {
+        values: AttributeStateStyle<T>,
+        animation: Box<AnimatedInteraction<C>>,
+    },
 }
 
-#[derive(Debug)]
-pub enum StyledAttribute {
-    BackgroundColor(AttributeStyle<Color, InteractiveBackground>),
-    Custom(fn(Entity, &mut World)),
+impl<T, C> AttributeStyle<T, C>
+where
+    T: Lerp + Clone,
+    C: Component + ComponentController<TargetType = T>,
+{
+    fn base_value(&self) -> T {
+        match self {
+            AttributeStyle::Static(value) => value.clone(),
+            AttributeStyle::PerState(values) => values.default.clone(),
+            AttributeStyle::Animated { values, .. } => values.default.clone(),
+        }
+    }
+}
+
+macro_rules! styled_attributes {
+    ($($(#[$meta:meta])* ($variant:ident, $target:ty, $controller:ident)),* $(,)?) => {
+        #[derive(Clone, Debug, Deserialize)]
+        pub enum StyledAttribute {
+            $($(#[$meta])* $variant(AttributeStyle<$target, $controller>),)*
+            FluxInteraction(bool),
+            #[serde(skip)]
+            Custom(fn(Entity, &mut World)),
+        }
+
+        impl StyledAttribute {
+            pub fn attribute(&self) -> Option<StylableAttribute> {
+                match self {
+                    $(StyledAttribute::$variant(_) => StylableAttribute::$variant.into(),)*
+                    StyledAttribute::FluxInteraction(_) => StylableAttribute::FluxInteraction.into(),
+                    StyledAttribute::Custom(_) => None,
+                }
+            }
+
+            fn apply(&self, entity: Entity, commands: &mut Commands) {
+                match self {
+                    $(StyledAttribute::$variant(style) => {
+                        commands.entity(entity).add(ApplyAttributeStyle {
+                            attribute: StylableAttribute::$variant,
+                            style: style.clone(),
+                        });
+                    })*
+                    StyledAttribute::FluxInteraction(enabled) => {
+                        commands.style(entity).flux_interaction_enabled(*enabled);
+                    }
+                    StyledAttribute::Custom(callback) => {
+                        commands.entity(entity).add(*callback);
+                    }
+                }
+            }
+
+            /// Removes the interactions and animations added by the attribute, the value stays
+            fn clear(&self, entity: Entity, commands: &mut Commands) {
+                match self {
+                    $(StyledAttribute::$variant(_) => {
+                        commands.entity(entity).add(ClearAttributeStyle::<$controller> {
+                            attribute: StylableAttribute::$variant,
+                            context: PhantomData,
+                        });
+                    })*
+                    StyledAttribute::FluxInteraction(_) => {
+                        commands.style(entity).flux_interaction_enabled(true);
+                    }
+                    StyledAttribute::Custom(_) => (),
+                }
+            }
+        }
+    };
 }
 
+// Discrete values (i.e. `Display`) switch halfway through animations.
+// Skipped variants have no serde support in bevy and can only be set from code.
+styled_attributes!(
+    (Display, Display, InteractiveDisplay),
+    (PositionType, PositionType, InteractivePositionType),
+    (Overflow, Overflow, InteractiveOverflow),
+    (Direction, Direction, InteractiveDirection),
+    (Left, Val, InteractiveLeft),
+    (Right, Val, InteractiveRight),
+    (Top, Val, InteractiveTop),
+    (Bottom, Val, InteractiveBottom),
+    (Width, Val, InteractiveWidth),
+    (Height, Val, InteractiveHeight),
+    (MinWidth, Val, InteractiveMinWidth),
+    (MinHeight, Val, InteractiveMinHeight),
+    (AspectRatio, Option<f32>, InteractiveAspectRatio),
+    (AlignItems, AlignItems, InteractiveAlignItems),
+    (JustifyItems, JustifyItems, InteractiveJustifyItems),
+    (AlignSelf, AlignSelf, InteractiveAlignSelf),
+    (JustifySelf, JustifySelf, InteractiveJustifySelf),
+    (AlignContent, AlignContent, InteractiveAlignContent),
+    (JustifyContent, JustifyContent, InteractiveJustifyContent),
+    (Margin, UiRect, InteractiveMargin),
+    (Padding, UiRect, InteractivePadding),
+    (Border, UiRect, InteractiveBorderSize),
+    (FlexDirection, FlexDirection, InteractiveFlexDirection),
+    (FlexWrap, FlexWrap, InteractiveFlexWrap),
+    (FlexGrow, f32, InteractiveFlexGrow),
+    (FlexShrink, f32, InteractiveFlexShrink),
+    (FlexBasis, Val, InteractiveFlexBasis),
+    (RowGap, Val, InteractiveRowGap),
+    (ColumnGap, Val, InteractiveColumnGap),
+    (GridAutoFlow, GridAutoFlow, InteractiveGridAutoFlow),
+    (
+        GridTemplateRows,
+        Vec<RepeatedGridTrack>,
+        InteractiveGridTemplateRows
+    ),
+    (
+        GridTemplateColumns,
+        Vec<RepeatedGridTrack>,
+        InteractiveGridTemplateColumns
+    ),
+    (GridAutoRows, Vec<GridTrack>, InteractiveGridAutoRows),
+    (GridAutoColumns, Vec<GridTrack>, InteractiveGridAutoColumns),
+    (GridRow, GridPlacement, InteractiveGridRow),
+    (GridColumn, GridPlacement, InteractiveGridColumn),
+    (BackgroundColor, Color, InteractiveBackground),
+    (BorderColor, Color, InteractiveBorderColor),
+    (FocusPolicy, FocusPolicy, InteractiveFocusPolicy),
+    #[serde(skip)]
+    (Visibility, Visibility, InteractiveVisibility),
+    #[serde(skip)]
+    (ZIndex, ZIndex, InteractiveZIndex),
+    #[serde(skip)]
+    (Image, Handle<Image>, InteractiveImage),
+    #[serde(skip)]
+    (ImageScaleMode, ImageScaleMode, InteractiveImageScaleMode),
+);
+
 impl PartialEq for StyledAttribute {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Self::BackgroundColor(_), Self::BackgroundColor(_)) => true,
             (Self::Custom(l0), Self::Custom(r0)) => l0 == r0,
-            _ => false,
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+struct ApplyAttributeStyle<T, C>
+where
+    T: Lerp,
+    C: Component + ComponentController<TargetType = T>,
+{
+    attribute: StylableAttribute,
+    style: AttributeStyle<T, C>,
+}
+
+impl<T, C, S, P> EntityCommand for ApplyAttributeStyle<T, C>
+where
+    T: Lerp + Clone + Send + Sync + 'static,
+    C: Component
+        + Clone
+        + Default
+        + Reflect
+        + InteractionConfig<TargetType = T>
+        + ComponentController<TargetType = T, InteractionState = S, ControlledComponent = P>,
+    S: Component + InteractionState<TargetType = T>,
+    P: Component,
+{
+    fn apply(self, entity: Entity, world: &mut World) {
+        if let Some(locked_attrs) = world.get::<LockedStyleAttributes>(entity) {
+            if locked_attrs.contains(self.attribute) {
+                return;
+            }
+        }
+
+        if !set_base_value::<C>(entity, world, self.style.base_value()) {
+            warn!(
+                "Failed to apply theme {:?} to entity {:?}: No controlled component found!",
+                self.attribute, entity
+            );
+            return;
+        }
+
+        let mut entity_mut = world.entity_mut(entity);
+        match self.style {
+            AttributeStyle::Static(_) => {
+                entity_mut.remove::<(C, S, AnimatedInteraction<C>, AnimatedInteractionState<C>)>();
+            }
+            AttributeStyle::PerState(values) => {
+                entity_mut
+                    .insert(values.controller::<C>())
+                    .remove::<(AnimatedInteraction<C>, AnimatedInteractionState<C>)>();
+            }
+            AttributeStyle::Animated { values, animation } => {
+                entity_mut.insert((values.controller::<C>(), *animation));
+            }
+        }
+
+        if !entity_mut.contains::<FluxInteraction>() {
+            if !entity_mut.contains::<Interaction>() {
+                entity_mut.insert(Interaction::default());
+            }
+
+            entity_mut.insert(TrackedInteraction::default());
+        }
+    }
+}
+
+struct ClearAttributeStyle<C> {
+    attribute: StylableAttribute,
+    context: PhantomData<C>,
+}
+
+impl<C, S> EntityCommand for ClearAttributeStyle<C>
+where
+    C: Component + Default + Reflect + ComponentController<InteractionState = S>,
+    S: Component,
+{
+    fn apply(self, entity: Entity, world: &mut World) {
+        if let Some(locked_attrs) = world.get::<LockedStyleAttributes>(entity) {
+            if locked_attrs.contains(self.attribute) {
+                return;
+            }
         }
+
+        world
+            .entity_mut(entity)
+            .remove::<(C, S, AnimatedInteraction<C>, AnimatedInteractionState<C>)>();
     }
 }
 
+/// Sets the value interactions return to. Returns `false` if the controlled component is missing.
+pub(crate) fn set_base_value<C>(entity: Entity, world: &mut World, value: C::TargetType) -> bool
+where
+    C: ComponentController,
+    C::TargetType: Clone,
+    C::InteractionState: Component + InteractionState<TargetType = C::TargetType>,
+    C::ControlledComponent: Component,
+{
+    let Some(controlled_component) = world.get_mut::<C::ControlledComponent>(entity) else {
+        return false;
+    };
+
+    C::update_controlled_component(controlled_component, value.clone());
+
+    if let Some(mut state) = world.get_mut::<C::InteractionState>(entity) {
+        state.set_original(value.clone());
+        state.set_transition_base(value);
+    }
+
+    true
+}
+
 #[derive(Component, Debug, Default)]
 pub struct LockedStyleAttributes(HashSet<StylableAttribute>);
 
 impl LockedStyleAttributes {
+    pub fn from_vec(attributes: Vec<StylableAttribute>) -> Self {
+        Self(attributes.into_iter().collect())
+    }
+
     pub fn contains(&self, attr: StylableAttribute) -> bool {
         self.0.contains(&attr)
     }
@@ -113,10 +669,35 @@ where
     style: Vec<StyledAttribute>,
 }
 
+impl<C> Default for Theme<C>
+where
+    C: Component,
+{
+    fn default() -> Self {
+        Self {
+            context: Default::default(),
+            style: Default::default(),
+        }
+    }
+}
+
 impl<C> Theme<C>
 where
     C: Component,
 {
+    pub fn new(style: Vec<StyledAttribute>) -> Self {
+        let mut theme = Self::default();
+        for attribute in style {
+            theme.add_style(attribute);
+        }
+
+        theme
+    }
+
+    pub fn style(&self) -> &Vec<StyledAttribute> {
+        &self.style
+    }
+
     pub fn add_style(&mut self, attribute: StyledAttribute) {
         if !self.style.contains(&attribute) {
             self.style.push(attribute);
@@ -130,26 +711,56 @@ where
     }
 }
 
-// fn apply_theme<C>(
-//     q_added_targets: Query<Entity, Added<C>>,
-//     q_target: Query<(Entity, &C)>,
-//     q_theme: Query<&Theme<C>>,
-//     q_changed_theme: Query<(Entity, &Theme<C>), Changed<Theme<C>>>,
-//     q_parent: Query<&Parent>,
-//     mut commands: Commands,
-// ) where
-//     C: Component,
-// {
-// }
-
-// fn follow_theme<C>(
-//     q_changed_theme: Query<(Entity, &Theme<C>), Changed<Theme<C>>>,
-//     q_target: Query<(Entity, &C)>,
-//     q_parent: Query<&Parent>,
-// ) where
-//     C: Component,
-// {
-// }
+/// Themes loaded from `*.theme.ron` files.
+///
+/// The file is a map of component names (i.e. `"Checkbox"`) to the list of
+/// [`StyledAttribute`]s of their [`Theme<C>`]. Insert the loaded `Handle<ThemeAsset>` on
+/// the root entity of the themed hierarchy. With bevy's `file_watcher` feature enabled,
+/// changes to the file are re-applied to all themed entities.
+#[derive(Asset, TypePath, Debug, Deserialize)]
+#[serde(transparent)]
+pub struct ThemeAsset(HashMap<String, Vec<StyledAttribute>>);
+
+impl ThemeAsset {
+    pub fn style_for<C: Component>(&self) -> Option<&Vec<StyledAttribute>> {
+        self.0.get(&get_short_name(std::any::type_name::<C>()))
+    }
+}
+
+#[derive(Default)]
+pub struct ThemeAssetLoader;
+
+#[derive(Debug, Error)]
+pub enum ThemeAssetLoaderError {
+    #[error("Could not load theme: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse theme: {0}")]
+    RonSpannedError(#[from] ron::error::SpannedError),
+}
+
+impl AssetLoader for ThemeAssetLoader {
+    type Asset = ThemeAsset;
+    type Settings = ();
+    type Error = ThemeAssetLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let theme = ron::de::from_bytes::<ThemeAsset>(&bytes)?;
+            Ok(theme)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["theme.ron"]
+    }
+}
 
 /*
 
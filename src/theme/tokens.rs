@@ -0,0 +1,478 @@
+use bevy::{ecs::system::EntityCommand, prelude::*};
+
+use crate::interactions::{
+    InteractiveBackground, InteractiveBorderColor, InteractiveMargin, InteractivePadding,
+};
+
+use super::{set_base_value, LockedStyleAttributes, StylableAttribute};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum ColorScheme {
+    #[default]
+    Dark,
+    Light,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum ColorToken {
+    Surface,
+    SurfaceVariant,
+    SurfaceRaised,
+    OnSurface,
+    Outline,
+    OutlineVariant,
+    Input,
+    OnInput,
+    Accent,
+    OnAccent,
+    Highlight,
+    Header,
+    Active,
+    Danger,
+}
+
+#[derive(Clone, Debug, Reflect)]
+pub struct ColorPalette {
+    pub surface: Color,
+    pub surface_variant: Color,
+    pub surface_raised: Color,
+    pub on_surface: Color,
+    pub outline: Color,
+    pub outline_variant: Color,
+    pub input: Color,
+    pub on_input: Color,
+    pub accent: Color,
+    pub on_accent: Color,
+    pub highlight: Color,
+    pub header: Color,
+    pub active: Color,
+    pub danger: Color,
+}
+
+impl ColorPalette {
+    pub fn dark() -> Self {
+        Self {
+            surface: Color::rgb(0.1, 0.1, 0.1),
+            surface_variant: Color::rgb(0.15, 0.155, 0.16),
+            surface_raised: Color::GRAY,
+            on_surface: Color::ANTIQUE_WHITE,
+            outline: Color::DARK_GRAY,
+            outline_variant: Color::GRAY,
+            input: Color::ANTIQUE_WHITE,
+            on_input: Color::DARK_GRAY,
+            accent: Color::AQUAMARINE,
+            on_accent: Color::BLACK,
+            highlight: Color::rgba(0., 1., 1., 0.3),
+            header: Color::DARK_GRAY,
+            active: Color::DARK_GRAY,
+            danger: Color::rgb(0.8, 0.2, 0.2),
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            surface: Color::rgb(0.95, 0.95, 0.95),
+            surface_variant: Color::rgb(0.88, 0.89, 0.9),
+            surface_raised: Color::rgb(0.78, 0.8, 0.83),
+            on_surface: Color::rgb(0.1, 0.1, 0.12),
+            outline: Color::rgb(0.7, 0.7, 0.72),
+            outline_variant: Color::rgb(0.55, 0.55, 0.58),
+            input: Color::WHITE,
+            on_input: Color::rgb(0.2, 0.2, 0.22),
+            accent: Color::rgb(0., 0.5, 0.6),
+            on_accent: Color::WHITE,
+            highlight: Color::rgba(0., 0.5, 0.6, 0.25),
+            header: Color::rgb(0.8, 0.82, 0.85),
+            active: Color::rgb(0.82, 0.84, 0.87),
+            danger: Color::rgb(0.75, 0.1, 0.1),
+        }
+    }
+
+    pub fn get(&self, token: ColorToken) -> Color {
+        match token {
+            ColorToken::Surface => self.surface,
+            ColorToken::SurfaceVariant => self.surface_variant,
+            ColorToken::SurfaceRaised => self.surface_raised,
+            ColorToken::OnSurface => self.on_surface,
+            ColorToken::Outline => self.outline,
+            ColorToken::OutlineVariant => self.outline_variant,
+            ColorToken::Input => self.input,
+            ColorToken::OnInput => self.on_input,
+            ColorToken::Accent => self.accent,
+            ColorToken::OnAccent => self.on_accent,
+            ColorToken::Highlight => self.highlight,
+            ColorToken::Header => self.header,
+            ColorToken::Active => self.active,
+            ColorToken::Danger => self.danger,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum SpacingToken {
+    ExtraSmall,
+    Small,
+    Medium,
+    Large,
+    ExtraLarge,
+}
+
+#[derive(Clone, Debug, Reflect)]
+pub struct SpacingScale {
+    pub extra_small: f32,
+    pub small: f32,
+    pub medium: f32,
+    pub large: f32,
+    pub extra_large: f32,
+}
+
+impl Default for SpacingScale {
+    fn default() -> Self {
+        Self {
+            extra_small: 2.,
+            small: 5.,
+            medium: 8.,
+            large: 16.,
+            extra_large: 24.,
+        }
+    }
+}
+
+impl SpacingScale {
+    pub fn get(&self, token: SpacingToken) -> f32 {
+        match token {
+            SpacingToken::ExtraSmall => self.extra_small,
+            SpacingToken::Small => self.small,
+            SpacingToken::Medium => self.medium,
+            SpacingToken::Large => self.large,
+            SpacingToken::ExtraLarge => self.extra_large,
+        }
+    }
+}
+
+/// Spacing tokens per side, sides without a token have no spacing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub struct TokenRect {
+    pub left: Option<SpacingToken>,
+    pub right: Option<SpacingToken>,
+    pub top: Option<SpacingToken>,
+    pub bottom: Option<SpacingToken>,
+}
+
+impl TokenRect {
+    pub fn all(token: SpacingToken) -> Self {
+        Self {
+            left: Some(token),
+            right: Some(token),
+            top: Some(token),
+            bottom: Some(token),
+        }
+    }
+
+    pub fn horizontal(token: SpacingToken) -> Self {
+        Self {
+            left: Some(token),
+            right: Some(token),
+            ..default()
+        }
+    }
+
+    pub fn vertical(token: SpacingToken) -> Self {
+        Self {
+            top: Some(token),
+            bottom: Some(token),
+            ..default()
+        }
+    }
+
+    pub fn left(token: SpacingToken) -> Self {
+        Self {
+            left: Some(token),
+            ..default()
+        }
+    }
+
+    pub fn right(token: SpacingToken) -> Self {
+        Self {
+            right: Some(token),
+            ..default()
+        }
+    }
+
+    pub fn top(token: SpacingToken) -> Self {
+        Self {
+            top: Some(token),
+            ..default()
+        }
+    }
+
+    pub fn bottom(token: SpacingToken) -> Self {
+        Self {
+            bottom: Some(token),
+            ..default()
+        }
+    }
+}
+
+impl From<SpacingToken> for TokenRect {
+    fn from(token: SpacingToken) -> Self {
+        Self::all(token)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum FontSizeToken {
+    Small,
+    Medium,
+    Large,
+}
+
+#[derive(Clone, Debug, Reflect)]
+pub struct FontSizeScale {
+    pub small: f32,
+    pub medium: f32,
+    pub large: f32,
+}
+
+impl Default for FontSizeScale {
+    fn default() -> Self {
+        Self {
+            small: 12.,
+            medium: 14.,
+            large: 18.,
+        }
+    }
+}
+
+impl FontSizeScale {
+    pub fn get(&self, token: FontSizeToken) -> f32 {
+        match token {
+            FontSizeToken::Small => self.small,
+            FontSizeToken::Medium => self.medium,
+            FontSizeToken::Large => self.large,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum FontToken {
+    Regular,
+    Bold,
+    Monospace,
+}
+
+/// Font handles used by the widgets. All default to bevy's built-in font.
+#[derive(Clone, Debug, Default, Reflect)]
+pub struct FontFamily {
+    pub regular: Handle<Font>,
+    pub bold: Handle<Font>,
+    pub monospace: Handle<Font>,
+}
+
+impl FontFamily {
+    pub fn get(&self, token: FontToken) -> Handle<Font> {
+        match token {
+            FontToken::Regular => self.regular.clone(),
+            FontToken::Bold => self.bold.clone(),
+            FontToken::Monospace => self.monospace.clone(),
+        }
+    }
+}
+
+/// Design tokens read by the built-in widgets.
+///
+/// Changing the resource (i.e. switching the `scheme`) restyles every entity with a [`TokenStyle`].
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct ThemeTokens {
+    pub scheme: ColorScheme,
+    pub dark: ColorPalette,
+    pub light: ColorPalette,
+    pub spacing: SpacingScale,
+    pub font_size: FontSizeScale,
+    pub font: FontFamily,
+}
+
+impl Default for ThemeTokens {
+    fn default() -> Self {
+        Self {
+            scheme: Default::default(),
+            dark: ColorPalette::dark(),
+            light: ColorPalette::light(),
+            spacing: Default::default(),
+            font_size: Default::default(),
+            font: Default::default(),
+        }
+    }
+}
+
+impl ThemeTokens {
+    pub fn palette(&self) -> &ColorPalette {
+        match self.scheme {
+            ColorScheme::Dark => &self.dark,
+            ColorScheme::Light => &self.light,
+        }
+    }
+
+    pub fn color(&self, token: ColorToken) -> Color {
+        self.palette().get(token)
+    }
+
+    pub fn spacing(&self, token: SpacingToken) -> Val {
+        Val::Px(self.spacing.get(token))
+    }
+
+    pub fn spacing_rect(&self, rect: TokenRect) -> UiRect {
+        let side = |token: Option<SpacingToken>| match token {
+            Some(token) => self.spacing(token),
+            None => Val::Px(0.),
+        };
+
+        UiRect::new(
+            side(rect.left),
+            side(rect.right),
+            side(rect.top),
+            side(rect.bottom),
+        )
+    }
+
+    pub fn text_style(&self, color: ColorToken, size: FontSizeToken) -> TextStyle {
+        TextStyle {
+            font: self.font.get(FontToken::Regular),
+            font_size: self.font_size.get(size),
+            color: self.color(color),
+        }
+    }
+
+    pub fn toggle_scheme(&mut self) {
+        self.scheme = match self.scheme {
+            ColorScheme::Dark => ColorScheme::Light,
+            ColorScheme::Light => ColorScheme::Dark,
+        };
+    }
+}
+
+/// Token references resolved against [`ThemeTokens`].
+///
+/// Applied when added and whenever the tokens change. Themes applied to the same entity
+/// take precedence over the tokens.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct TokenStyle {
+    pub background: Option<ColorToken>,
+    pub border: Option<ColorToken>,
+    pub highlight: Option<ColorToken>,
+    /// Background while the entity has the [`PseudoState::Active`](crate::interactions::PseudoState) state
+    pub active: Option<ColorToken>,
+    pub text: Option<ColorToken>,
+    pub font_size: Option<FontSizeToken>,
+    pub font: Option<FontToken>,
+    pub padding: Option<TokenRect>,
+    pub margin: Option<TokenRect>,
+}
+
+pub(super) fn apply_token_style(
+    q_styles: Query<(Entity, Ref<TokenStyle>)>,
+    tokens: Res<ThemeTokens>,
+    mut commands: Commands,
+) {
+    for (entity, style) in &q_styles {
+        if !tokens.is_changed() && !style.is_changed() {
+            continue;
+        }
+
+        commands.entity(entity).add(ApplyTokenStyle {
+            background: style.background.map(|token| tokens.color(token)),
+            border: style.border.map(|token| tokens.color(token)),
+            highlight: style.highlight.map(|token| tokens.color(token)),
+            active: style.active.map(|token| tokens.color(token)),
+            text: style.text.map(|token| tokens.color(token)),
+            font_size: style.font_size.map(|token| tokens.font_size.get(token)),
+            font: style.font.map(|token| tokens.font.get(token)),
+            padding: style.padding.map(|rect| tokens.spacing_rect(rect)),
+            margin: style.margin.map(|rect| tokens.spacing_rect(rect)),
+        });
+    }
+}
+
+struct ApplyTokenStyle {
+    background: Option<Color>,
+    border: Option<Color>,
+    highlight: Option<Color>,
+    active: Option<Color>,
+    text: Option<Color>,
+    font_size: Option<f32>,
+    font: Option<Handle<Font>>,
+    padding: Option<UiRect>,
+    margin: Option<UiRect>,
+}
+
+impl EntityCommand for ApplyTokenStyle {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let is_locked = |world: &World, attribute: StylableAttribute| {
+            world
+                .get::<LockedStyleAttributes>(entity)
+                .is_some_and(|locked_attrs| locked_attrs.contains(attribute))
+        };
+
+        if let Some(background) = self.background {
+            if !is_locked(world, StylableAttribute::BackgroundColor) {
+                set_base_value::<InteractiveBackground>(entity, world, background);
+            }
+        }
+
+        if let Some(border) = self.border {
+            if !is_locked(world, StylableAttribute::BorderColor) {
+                set_base_value::<InteractiveBorderColor>(entity, world, border);
+            }
+        }
+
+        if let Some(padding) = self.padding {
+            if !is_locked(world, StylableAttribute::Padding) {
+                set_base_value::<InteractivePadding>(entity, world, padding);
+            }
+        }
+
+        if let Some(margin) = self.margin {
+            if !is_locked(world, StylableAttribute::Margin) {
+                set_base_value::<InteractiveMargin>(entity, world, margin);
+            }
+        }
+
+        if self.highlight.is_some() || self.active.is_some() {
+            if let Some(mut interactive_background) = world.get_mut::<InteractiveBackground>(entity)
+            {
+                if let Some(highlight) = self.highlight {
+                    interactive_background.highlight = highlight.into();
+                }
+                if let Some(active) = self.active {
+                    interactive_background.active = active.into();
+                }
+            }
+        }
+
+        if self.text.is_none() && self.font_size.is_none() && self.font.is_none() {
+            return;
+        }
+
+        let Some(mut text) = world.get_mut::<Text>(entity) else {
+            warn!(
+                "Failed to apply text tokens to entity {:?}: No Text component found!",
+                entity
+            );
+            return;
+        };
+
+        for section in text.sections.iter_mut() {
+            if let Some(color) = self.text {
+                section.style.color = color;
+            }
+            if let Some(font_size) = self.font_size {
+                section.style.font_size = font_size;
+            }
+            if let Some(font) = &self.font {
+                section.style.font = font.clone();
+            }
+        }
+    }
+}
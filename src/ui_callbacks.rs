@@ -0,0 +1,263 @@
+use std::marker::PhantomData;
+
+use bevy::{
+    ecs::{
+        entity::EntityHashMap,
+        system::{EntityCommand, SystemId},
+    },
+    prelude::*,
+};
+
+use crate::{ui_builder::UiBuilder, widgets::WidgetLibraryUpdate, Clicked, FluxInteraction};
+
+pub struct UiCallbacksPlugin;
+
+impl Plugin for UiCallbacksPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiCallbackSystems>()
+            .add_event::<UiCallbackTriggered>()
+            .add_systems(
+                Update,
+                run_ui_callbacks
+                    .in_set(UiCallbackUpdate)
+                    .after(WidgetLibraryUpdate),
+            )
+            .add_systems(PostUpdate, remove_unused_callback_systems);
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct UiCallbackUpdate;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UiCallbackTrigger {
+    /// The node is clicked or, for menu items, activated
+    Press,
+    /// The widget's value changed, see the widget events
+    Change,
+    /// The pointer entered the node
+    Hover,
+}
+
+/// One-shot systems registered on a node, run with the node as input.
+#[derive(Component, Clone, Debug, Default)]
+pub struct UiCallbacks {
+    on_press: Option<SystemId<Entity>>,
+    on_change: Option<SystemId<Entity>>,
+    on_hover: Option<SystemId<Entity>>,
+}
+
+impl UiCallbacks {
+    pub fn get(&self, trigger: UiCallbackTrigger) -> Option<SystemId<Entity>> {
+        match trigger {
+            UiCallbackTrigger::Press => self.on_press,
+            UiCallbackTrigger::Change => self.on_change,
+            UiCallbackTrigger::Hover => self.on_hover,
+        }
+    }
+
+    fn set(
+        &mut self,
+        trigger: UiCallbackTrigger,
+        system_id: SystemId<Entity>,
+    ) -> Option<SystemId<Entity>> {
+        let slot = match trigger {
+            UiCallbackTrigger::Press => &mut self.on_press,
+            UiCallbackTrigger::Change => &mut self.on_change,
+            UiCallbackTrigger::Hover => &mut self.on_hover,
+        };
+
+        slot.replace(system_id)
+    }
+}
+
+/// Callback systems registered per node, kept until the node's [`UiCallbacks`] are removed.
+#[derive(Resource, Debug, Default)]
+struct UiCallbackSystems(EntityHashMap<Vec<SystemId<Entity>>>);
+
+/// Sent by widgets for nodes whose callbacks should run
+#[derive(Event, Clone, Copy, Debug)]
+pub struct UiCallbackTriggered {
+    pub entity: Entity,
+    pub trigger: UiCallbackTrigger,
+}
+
+/// Widget events forwarded as [`UiCallbackTriggered`] by [`report_ui_callbacks`]
+pub trait UiCallbackEvent: Event {
+    const TRIGGER: UiCallbackTrigger;
+
+    fn entity(&self) -> Entity;
+}
+
+/// Forwards `E` to [`UiCallbackTriggered`], added by the plugin sending `E`
+pub fn report_ui_callbacks<E>(
+    mut events: EventReader<E>,
+    mut e_triggered: EventWriter<UiCallbackTriggered>,
+) where
+    E: UiCallbackEvent,
+{
+    e_triggered.send_batch(events.read().map(|event| UiCallbackTriggered {
+        entity: event.entity(),
+        trigger: E::TRIGGER,
+    }));
+}
+
+fn run_ui_callbacks(
+    q_callbacks: Query<&UiCallbacks>,
+    q_interaction: Query<(Entity, Ref<FluxInteraction>), With<UiCallbacks>>,
+    mut e_clicked: EventReader<Clicked>,
+    mut e_triggered: EventReader<UiCallbackTriggered>,
+    mut commands: Commands,
+) {
+    let hovered = q_interaction
+        .iter()
+        .filter(|(_, interaction)| {
+            interaction.is_changed() && **interaction == FluxInteraction::PointerEnter
+        })
+        .map(|(entity, _)| (entity, UiCallbackTrigger::Hover));
+
+    let mut triggered: Vec<(Entity, UiCallbackTrigger)> = e_clicked
+        .read()
+        .map(|event| (event.entity, UiCallbackTrigger::Press))
+        .chain(
+            e_triggered
+                .read()
+                .map(|event| (event.entity, event.trigger)),
+        )
+        .chain(hovered)
+        .collect();
+
+    // A released menu item is both clicked and activated, and callbacks read the current
+    // value, so once per frame is enough
+    triggered.sort();
+    triggered.dedup();
+
+    for (entity, trigger) in triggered {
+        let Ok(callbacks) = q_callbacks.get(entity) else {
+            continue;
+        };
+
+        if let Some(system_id) = callbacks.get(trigger) {
+            commands.run_system_with_input(system_id, entity);
+        }
+    }
+}
+
+fn remove_unused_callback_systems(
+    mut removed: RemovedComponents<UiCallbacks>,
+    mut registered: ResMut<UiCallbackSystems>,
+    mut commands: Commands,
+) {
+    for entity in removed.read() {
+        let Some(system_ids) = registered.0.remove(&entity) else {
+            continue;
+        };
+
+        commands.add(move |world: &mut World| {
+            for system_id in system_ids {
+                if let Err(error) = world.remove_system(system_id) {
+                    warn!("Failed to remove callback system: {:?}", error);
+                }
+            }
+        });
+    }
+}
+
+struct SetUiCallback<S, M> {
+    trigger: UiCallbackTrigger,
+    system: S,
+    _marker: PhantomData<fn() -> M>,
+}
+
+impl<S, M> EntityCommand for SetUiCallback<S, M>
+where
+    S: IntoSystem<Entity, (), M> + Send + 'static,
+    M: 'static,
+{
+    fn apply(self, entity: Entity, world: &mut World) {
+        if world.get_entity(entity).is_none() {
+            warn!(
+                "Failed to set {:?} callback on entity {:?}: Entity does not exist!",
+                self.trigger, entity
+            );
+            return;
+        }
+
+        let system_id = world.register_system(self.system);
+        let replaced = match world.get_mut::<UiCallbacks>(entity) {
+            Some(mut callbacks) => callbacks.set(self.trigger, system_id),
+            None => {
+                let mut callbacks = UiCallbacks::default();
+                callbacks.set(self.trigger, system_id);
+                world.entity_mut(entity).insert(callbacks);
+                None
+            }
+        };
+
+        let mut registered = world.get_resource_or_insert_with(UiCallbackSystems::default);
+        let system_ids = registered.0.entry(entity).or_default();
+        system_ids.retain(|id| Some(*id) != replaced);
+        system_ids.push(system_id);
+
+        if let Some(replaced) = replaced {
+            if let Err(error) = world.remove_system(replaced) {
+                warn!("Failed to remove replaced callback system: {:?}", error);
+            }
+        }
+    }
+}
+
+pub trait UiCallbacksExt {
+    fn on_press<M: 'static>(
+        &mut self,
+        system: impl IntoSystem<Entity, (), M> + Send + 'static,
+    ) -> &mut Self;
+
+    fn on_change<M: 'static>(
+        &mut self,
+        system: impl IntoSystem<Entity, (), M> + Send + 'static,
+    ) -> &mut Self;
+
+    fn on_hover<M: 'static>(
+        &mut self,
+        system: impl IntoSystem<Entity, (), M> + Send + 'static,
+    ) -> &mut Self;
+}
+
+impl UiCallbacksExt for UiBuilder<'_, '_, '_, Entity> {
+    fn on_press<M: 'static>(
+        &mut self,
+        system: impl IntoSystem<Entity, (), M> + Send + 'static,
+    ) -> &mut Self {
+        self.entity_commands().add(SetUiCallback {
+            trigger: UiCallbackTrigger::Press,
+            system,
+            _marker: PhantomData,
+        });
+        self
+    }
+
+    fn on_change<M: 'static>(
+        &mut self,
+        system: impl IntoSystem<Entity, (), M> + Send + 'static,
+    ) -> &mut Self {
+        self.entity_commands().add(SetUiCallback {
+            trigger: UiCallbackTrigger::Change,
+            system,
+            _marker: PhantomData,
+        });
+        self
+    }
+
+    fn on_hover<M: 'static>(
+        &mut self,
+        system: impl IntoSystem<Entity, (), M> + Send + 'static,
+    ) -> &mut Self {
+        self.entity_commands().add(SetUiCallback {
+            trigger: UiCallbackTrigger::Hover,
+            system,
+            _marker: PhantomData,
+        });
+        self
+    }
+}
@@ -3,17 +3,18 @@ use bevy::{
     ecs::{
         component::ComponentInfo,
         entity::Entity,
-        query::With,
-        system::{Command, CommandQueue, Commands, EntityCommand, EntityCommands},
+        system::{CommandQueue, Commands, EntityCommand, EntityCommands, SystemState},
         world::{Mut, World},
     },
     hierarchy::Children,
     log::{info, warn},
     text::{Text, TextSection, TextStyle},
     ui::UiSurface,
-    window::{CursorIcon, PrimaryWindow, Window},
+    window::{CursorIcon, Window},
 };
 
+use crate::node_window::NodeWindow;
+
 struct SetTextSections {
     sections: Vec<TextSection>,
 }
@@ -85,15 +86,23 @@ impl<'a> SetTextExt<'a> for EntityCommands<'a> {
     }
 }
 
-// TODO: Move to style and apply to Node's window
+// TODO: Move to style
 struct SetCursor {
     cursor: CursorIcon,
 }
 
-impl Command for SetCursor {
-    fn apply(self, world: &mut World) {
-        let mut q_window = world.query_filtered::<&mut Window, With<PrimaryWindow>>();
-        let Ok(mut window) = q_window.get_single_mut(world) else {
+impl EntityCommand for SetCursor {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let mut system_state: SystemState<NodeWindow> = SystemState::new(world);
+        let Some(window) = system_state.get(world).window(entity) else {
+            warn!(
+                "Failed to set cursor for entity {:?}: Node is not rendered to a window!",
+                entity
+            );
+            return;
+        };
+
+        let Some(mut window) = world.get_mut::<Window>(window) else {
             return;
         };
 
@@ -103,13 +112,15 @@ impl Command for SetCursor {
     }
 }
 
-pub trait SetCursorExt<'w, 's, 'a> {
-    fn set_cursor(&'a mut self, cursor: CursorIcon);
+/// Sets the cursor icon of the window the node is rendered into.
+pub trait SetCursorExt<'a> {
+    fn set_cursor(&'a mut self, cursor: CursorIcon) -> &'a mut EntityCommands<'a>;
 }
 
-impl<'w, 's, 'a> SetCursorExt<'w, 's, 'a> for Commands<'w, 's> {
-    fn set_cursor(&'a mut self, cursor: CursorIcon) {
+impl<'a> SetCursorExt<'a> for EntityCommands<'a> {
+    fn set_cursor(&'a mut self, cursor: CursorIcon) -> &'a mut EntityCommands<'a> {
         self.add(SetCursor { cursor });
+        self
     }
 }
 
@@ -1,3 +1,5 @@
+pub mod animation;
+
 use bevy::{
     ecs::system::{EntityCommand, EntityCommands},
     prelude::*,
@@ -10,6 +12,8 @@ use crate::{
     FluxInteraction,
 };
 
+use self::animation::{transition_style, AnimatedStyleValue, StyleTransitions};
+
 pub struct UiStyle<'a> {
     commands: EntityCommands<'a>,
 }
@@ -82,46 +86,54 @@ struct SetNodeDirection {
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::Left)]
+#[animatable]
 struct SetNodeLeft {
     left: Val,
 }
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::Right)]
+#[animatable]
 struct SetNodeRight {
     right: Val,
 }
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::Top)]
+#[animatable]
 struct SetNodeTop {
     top: Val,
 }
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::Bottom)]
+#[animatable]
 struct SetNodeBottom {
     bottom: Val,
 }
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::Width)]
+#[animatable]
 struct SetNodeWidth {
     width: Val,
 }
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::Height)]
+#[animatable]
 struct SetNodeHeight {
     height: Val,
 }
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::MinWidth)]
+#[animatable]
 struct SetNodeMinWidth {
     min_width: Val,
 }
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::MinHeight)]
+#[animatable]
 struct SetNodeMinHeight {
     min_height: Val,
 }
@@ -168,18 +180,21 @@ struct SetNodeJustifyContents {
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::Margin)]
+#[animatable]
 struct SetNodeMargin {
     margin: UiRect,
 }
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::Padding)]
+#[animatable]
 struct SetNodePadding {
     padding: UiRect,
 }
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::Border)]
+#[animatable]
 struct SetNodeBorder {
     border: UiRect,
 }
@@ -198,30 +213,35 @@ struct SetNodeFlexWrap {
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::FlexGrow)]
+#[animatable]
 struct SetNodeFlexGrow {
     flex_grow: f32,
 }
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::FlexShrink)]
+#[animatable]
 struct SetNodeFlexShrink {
     flex_shrink: f32,
 }
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::FlexBasis)]
+#[animatable]
 struct SetNodeFlexBasis {
     flex_basis: Val,
 }
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::RowGap)]
+#[animatable]
 struct SetNodeRowGap {
     row_gap: Val,
 }
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::ColumnGap)]
+#[animatable]
 struct SetNodeColumnGap {
     column_gap: Val,
 }
@@ -272,6 +292,7 @@ struct SetNodeGridColumn {
 // TODO: Handle interactive original value for this and any other interactive attributes
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::BackgroundColor)]
+#[animatable]
 #[target_tupl(BackgroundColor)]
 struct SetBackgroundColor {
     background_color: Color,
@@ -279,6 +300,7 @@ struct SetBackgroundColor {
 
 #[derive(StyleCommand)]
 #[lock_attr(StylableAttribute::BorderColor)]
+#[animatable]
 #[target_tupl(BorderColor)]
 struct SetBorderColor {
     border_color: Color,
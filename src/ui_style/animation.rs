@@ -0,0 +1,590 @@
+use std::{
+    collections::VecDeque,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use bevy::{
+    ecs::system::{EntityCommand, EntityCommands},
+    prelude::*,
+    utils::HashMap,
+};
+use sickle_math::{color::ColorSpace, lerp::Lerp};
+
+use crate::{
+    animated_interaction::{AnimatedInteractionUpdate, AnimationConfig, AnimationProgress},
+    interactions::*,
+    theme::{set_base_value, LockedStyleAttributes, StylableAttribute},
+};
+
+use super::{UiStyle, UiStyleUnchecked};
+
+pub struct StyleAnimationPlugin;
+
+impl Plugin for StyleAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            StyleAnimationUpdate.after(AnimatedInteractionUpdate),
+        )
+        .add_event::<StyleAnimationFinished>()
+        .add_systems(Update, update_style_animations.in_set(StyleAnimationUpdate));
+    }
+}
+
+#[derive(SystemSet, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StyleAnimationUpdate;
+
+/// Identifies an animation started with [`UiStyle::animate`], see [`UiStyleAnimation::animation_id`]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct StyleAnimationId(u64);
+
+impl StyleAnimationId {
+    fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Sent once every step of an animation started with [`UiStyle::animate`] finished.
+///
+/// Not sent for animations interrupted by a transition of one of their attributes.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct StyleAnimationFinished {
+    pub entity: Entity,
+    pub animation: StyleAnimationId,
+}
+
+/// Tweens started through [`UiStyle::animate`]. Removed once all of them finished.
+#[derive(Component, Debug, Default)]
+pub struct StyleAnimation {
+    sequences: Vec<StyleAnimationSequence>,
+}
+
+impl StyleAnimation {
+    pub fn is_running(&self) -> bool {
+        !self.sequences.is_empty()
+    }
+}
+
+/// Attributes that interpolate to values set through [`UiStyle`] instead of snapping to them.
+///
+/// Locked attributes are not transitioned, the same way they are not set.
+#[derive(Component, Clone, Debug, Default)]
+pub struct StyleTransitions(HashMap<StylableAttribute, AnimationConfig>);
+
+impl StyleTransitions {
+    pub fn from_vec(transitions: Vec<(StylableAttribute, AnimationConfig)>) -> Self {
+        Self(transitions.into_iter().collect())
+    }
+
+    pub fn get(&self, attribute: StylableAttribute) -> Option<AnimationConfig> {
+        self.0.get(&attribute).copied()
+    }
+
+    pub fn set(&mut self, attribute: StylableAttribute, config: AnimationConfig) {
+        self.0.insert(attribute, config);
+    }
+
+    pub fn remove(&mut self, attribute: StylableAttribute) {
+        self.0.remove(&attribute);
+    }
+}
+
+/// Starts a tween from the current value, replacing running tweens of the same attribute
+pub(crate) fn transition_style(
+    entity: Entity,
+    world: &mut World,
+    to: AnimatedStyleValue,
+    config: AnimationConfig,
+    check_lock: bool,
+) {
+    let attribute = to.attribute();
+    let mut velocity = 0.;
+    if let Some(mut animation) = world.get_mut::<StyleAnimation>(entity) {
+        animation.sequences.retain_mut(|sequence| {
+            let Some(step) = sequence.steps.front_mut().filter(|step| step.started) else {
+                return true;
+            };
+            let Some(replaced) = step
+                .tweens
+                .iter()
+                .find(|tween| tween.to.attribute() == attribute)
+            else {
+                return true;
+            };
+
+            velocity = replaced.retarget_velocity(&to);
+            step.tweens
+                .retain(|tween| tween.to.attribute() != attribute);
+
+            // Transitions only tween this attribute, animations play on without finishing
+            if sequence.transition {
+                return false;
+            }
+
+            sequence.interrupted = true;
+            true
+        });
+    }
+
+    if to.current(entity, world).as_ref() == Some(&to) {
+        return;
+    }
+
+    let animation = StyleAnimationId::next();
+    StartStyleAnimation {
+        animation,
+        check_lock,
+        transition: true,
+    }
+    .apply(entity, world);
+    AddStyleTween {
+        animation,
+        to,
+        config,
+        velocity,
+    }
+    .apply(entity, world);
+}
+
+#[derive(Debug)]
+struct StyleAnimationSequence {
+    id: StyleAnimationId,
+    check_lock: bool,
+    /// Started by [`transition_style`] rather than [`UiStyle::animate`]
+    transition: bool,
+    /// A transition took over one of the tweens, the animation won't finish as built
+    interrupted: bool,
+    steps: VecDeque<StyleAnimationStep>,
+}
+
+impl StyleAnimationSequence {
+    /// Returns `true` once the last step finished
+    fn update(&mut self, entity: Entity, world: &mut World, delta: f32) -> bool {
+        let mut delta = delta;
+        while let Some(step) = self.steps.front_mut() {
+            if !step.update(entity, world, delta, self.check_lock) {
+                return false;
+            }
+
+            // The next step starts in the same frame
+            self.steps.pop_front();
+            delta = 0.;
+        }
+
+        true
+    }
+}
+
+#[derive(Debug, Default)]
+struct StyleAnimationStep {
+    delay: f32,
+    elapsed: f32,
+    started: bool,
+    tweens: Vec<StyleTween>,
+}
+
+impl StyleAnimationStep {
+    fn update(&mut self, entity: Entity, world: &mut World, delta: f32, check_lock: bool) -> bool {
+        if !self.started {
+            self.elapsed += delta;
+            if self.elapsed < self.delay {
+                return false;
+            }
+
+            self.started = true;
+            self.start(entity, world, check_lock);
+        }
+
+        let mut finished = true;
+        for tween in self.tweens.iter_mut() {
+            finished &= tween.update(entity, world, delta);
+        }
+
+        finished
+    }
+
+    fn start(&mut self, entity: Entity, world: &mut World, check_lock: bool) {
+        let locked_attrs = world.get::<LockedStyleAttributes>(entity);
+        self.tweens.retain_mut(|tween| {
+            let attribute = tween.to.attribute();
+            if check_lock && locked_attrs.is_some_and(|attrs| attrs.contains(attribute)) {
+                warn!(
+                    "Failed to animate {:?} property on entity {:?}: Attribute locked!",
+                    attribute, entity
+                );
+                return false;
+            }
+
+            // Tweens always start from the current value, not the one at the time of the call
+            tween.from = tween.to.current(entity, world);
+            if tween.from.is_none() {
+                warn!(
+                    "Failed to animate {:?} property on entity {:?}: No controlled component found!",
+                    attribute, entity
+                );
+            }
+
+            tween.from.is_some()
+        });
+    }
+}
+
+#[derive(Debug)]
+struct StyleTween {
+    from: Option<AnimatedStyleValue>,
+    to: AnimatedStyleValue,
+    config: AnimationConfig,
+    elapsed: f32,
+    progress: AnimationProgress,
+    velocity: f32,
+}
+
+impl StyleTween {
+    /// Velocity of a tween towards `to` starting at this tween's current value.
+    /// Reversing rescales it to the shorter way back.
+    fn retarget_velocity(&self, to: &AnimatedStyleValue) -> f32 {
+        let ratio = self.progress.ratio();
+        match self.from.as_ref() == Some(to) {
+            true if ratio > 0. => -self.velocity / ratio,
+            true => 0.,
+            false => self.velocity,
+        }
+    }
+
+    fn update(&mut self, entity: Entity, world: &mut World, delta: f32) -> bool {
+        let Some(from) = &self.from else {
+            return true;
+        };
+
+        let finished = match self.config.spring {
+            Some(spring) => {
+                let (position, velocity) =
+                    spring.step(self.progress.ratio(), self.velocity, 1., delta);
+                let at_rest = spring.is_at_rest(position, velocity, 1.);
+
+                self.velocity = velocity;
+                self.progress = match at_rest {
+                    true => AnimationProgress::End,
+                    false => AnimationProgress::Inbetween(position),
+                };
+                at_rest
+            }
+            None => {
+                self.elapsed = self.config.wrap_elapsed(self.elapsed + delta);
+                self.progress = self.config.progress(self.elapsed);
+                self.config.is_finished(self.elapsed)
+            }
+        };
+
+        let value = match self.progress {
+            AnimationProgress::Start => from.clone(),
+            AnimationProgress::Inbetween(tween_ratio) => from.lerp(
+                &self.to,
+                tween_ratio,
+                self.config.color_space.unwrap_or_default(),
+            ),
+            AnimationProgress::End => self.to.clone(),
+        };
+        value.apply(entity, world);
+
+        finished
+    }
+}
+
+fn update_style_animations(world: &mut World) {
+    let delta = world.resource::<Time<Real>>().delta_seconds();
+    let entities: Vec<Entity> = world
+        .query_filtered::<Entity, With<StyleAnimation>>()
+        .iter(world)
+        .collect();
+
+    for entity in entities {
+        let Some(mut animation) = world.get_mut::<StyleAnimation>(entity) else {
+            continue;
+        };
+
+        // Tweens need the world to read and write the animated components
+        let mut sequences = std::mem::take(&mut animation.sequences);
+        let mut finished = Vec::new();
+        sequences.retain_mut(|sequence| {
+            let running = !sequence.update(entity, world, delta);
+            if !running && !sequence.transition && !sequence.interrupted {
+                finished.push(sequence.id);
+            }
+
+            running
+        });
+
+        for animation in finished {
+            world.send_event(StyleAnimationFinished { entity, animation });
+        }
+
+        match sequences.is_empty() {
+            true => {
+                world.entity_mut(entity).remove::<StyleAnimation>();
+            }
+            false => {
+                if let Some(mut animation) = world.get_mut::<StyleAnimation>(entity) {
+                    animation.sequences = sequences;
+                }
+            }
+        }
+    }
+}
+
+macro_rules! animated_style_values {
+    ($(($variant:ident, $target:ty, $controller:ident, $setter:ident)),* $(,)?) => {
+        #[derive(Clone, Debug, PartialEq)]
+        pub(crate) enum AnimatedStyleValue {
+            $($variant($target),)*
+        }
+
+        impl AnimatedStyleValue {
+            fn attribute(&self) -> StylableAttribute {
+                match self {
+                    $(AnimatedStyleValue::$variant(_) => StylableAttribute::$variant,)*
+                }
+            }
+
+            fn current(&self, entity: Entity, world: &World) -> Option<Self> {
+                match self {
+                    $(AnimatedStyleValue::$variant(_) => world
+                        .get::<<$controller as ComponentController>::ControlledComponent>(entity)
+                        .map(|component| {
+                            AnimatedStyleValue::$variant($controller::extract_value(component))
+                        }),)*
+                }
+            }
+
+            fn lerp(&self, to: &Self, t: f32, color_space: ColorSpace) -> Self {
+                match (self, to) {
+                    $((AnimatedStyleValue::$variant(from), AnimatedStyleValue::$variant(to)) => {
+                        AnimatedStyleValue::$variant(from.lerp_in(*to, t, color_space))
+                    })*
+                    _ => to.clone(),
+                }
+            }
+
+            /// Sets the value as the base value of the attribute, so interactions return to it
+            fn apply(self, entity: Entity, world: &mut World) {
+                match self {
+                    $(AnimatedStyleValue::$variant(value) => {
+                        set_base_value::<$controller>(entity, world, value);
+                    })*
+                }
+            }
+        }
+
+        impl<'a> UiStyleAnimation<'a> {
+            $(pub fn $setter(&mut self, $setter: $target, config: AnimationConfig) -> &mut Self {
+                self.tween(AnimatedStyleValue::$variant($setter), config)
+            })*
+        }
+    };
+}
+
+animated_style_values!(
+    (Left, Val, InteractiveLeft, left),
+    (Right, Val, InteractiveRight, right),
+    (Top, Val, InteractiveTop, top),
+    (Bottom, Val, InteractiveBottom, bottom),
+    (Width, Val, InteractiveWidth, width),
+    (Height, Val, InteractiveHeight, height),
+    (MinWidth, Val, InteractiveMinWidth, min_width),
+    (MinHeight, Val, InteractiveMinHeight, min_height),
+    (Margin, UiRect, InteractiveMargin, margin),
+    (Padding, UiRect, InteractivePadding, padding),
+    (Border, UiRect, InteractiveBorderSize, border),
+    (FlexGrow, f32, InteractiveFlexGrow, flex_grow),
+    (FlexShrink, f32, InteractiveFlexShrink, flex_shrink),
+    (FlexBasis, Val, InteractiveFlexBasis, flex_basis),
+    (RowGap, Val, InteractiveRowGap, row_gap),
+    (ColumnGap, Val, InteractiveColumnGap, column_gap),
+    (
+        BackgroundColor,
+        Color,
+        InteractiveBackground,
+        background_color
+    ),
+    (BorderColor, Color, InteractiveBorderColor, border_color),
+);
+
+struct StartStyleAnimation {
+    animation: StyleAnimationId,
+    check_lock: bool,
+    transition: bool,
+}
+
+impl EntityCommand for StartStyleAnimation {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+            warn!(
+                "Failed to start style animation on entity {:?}: Entity does not exist!",
+                entity
+            );
+            return;
+        };
+
+        let sequence = StyleAnimationSequence {
+            id: self.animation,
+            check_lock: self.check_lock,
+            transition: self.transition,
+            interrupted: false,
+            steps: VecDeque::from([StyleAnimationStep::default()]),
+        };
+
+        match entity_mut.get_mut::<StyleAnimation>() {
+            Some(mut animation) => animation.sequences.push(sequence),
+            None => {
+                entity_mut.insert(StyleAnimation {
+                    sequences: vec![sequence],
+                });
+            }
+        }
+    }
+}
+
+struct AddStyleAnimationStep {
+    animation: StyleAnimationId,
+    delay: f32,
+}
+
+impl EntityCommand for AddStyleAnimationStep {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let Some(steps) = sequence_steps(entity, self.animation, world) else {
+            return;
+        };
+
+        match steps.back_mut() {
+            Some(step) if step.tweens.is_empty() => step.delay += self.delay.max(0.),
+            _ => steps.push_back(StyleAnimationStep {
+                delay: self.delay.max(0.),
+                ..default()
+            }),
+        }
+    }
+}
+
+struct AddStyleTween {
+    animation: StyleAnimationId,
+    to: AnimatedStyleValue,
+    config: AnimationConfig,
+    velocity: f32,
+}
+
+impl EntityCommand for AddStyleTween {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let Some(step) =
+            sequence_steps(entity, self.animation, world).and_then(|steps| steps.back_mut())
+        else {
+            return;
+        };
+
+        step.tweens.push(StyleTween {
+            from: None,
+            to: self.to,
+            config: self.config,
+            elapsed: 0.,
+            progress: AnimationProgress::Start,
+            velocity: self.velocity,
+        });
+    }
+}
+
+fn sequence_steps(
+    entity: Entity,
+    id: StyleAnimationId,
+    world: &mut World,
+) -> Option<&mut VecDeque<StyleAnimationStep>> {
+    let Some(animation) = world.get_mut::<StyleAnimation>(entity) else {
+        warn!(
+            "Failed to add to style animation on entity {:?}: No StyleAnimation found!",
+            entity
+        );
+        return None;
+    };
+
+    animation
+        .into_inner()
+        .sequences
+        .iter_mut()
+        .find(|sequence| sequence.id == id)
+        .map(|sequence| &mut sequence.steps)
+}
+
+/// Builds a sequence of tweens, interpolating from the current values.
+///
+/// Tweens added one after the other play in parallel, [`UiStyleAnimation::then`] and
+/// [`UiStyleAnimation::delay`] start a new step that waits for the previous one to finish.
+pub struct UiStyleAnimation<'a> {
+    animation: StyleAnimationId,
+    commands: EntityCommands<'a>,
+}
+
+impl<'a> UiStyleAnimation<'a> {
+    pub fn id(&self) -> Entity {
+        self.commands.id()
+    }
+
+    /// The id sent with [`StyleAnimationFinished`] once this animation finished
+    pub fn animation_id(&self) -> StyleAnimationId {
+        self.animation
+    }
+
+    pub fn then(&mut self) -> &mut Self {
+        self.delay(0.)
+    }
+
+    pub fn delay(&mut self, seconds: f32) -> &mut Self {
+        self.commands.add(AddStyleAnimationStep {
+            animation: self.animation,
+            delay: seconds,
+        });
+        self
+    }
+
+    fn tween(&mut self, to: AnimatedStyleValue, config: AnimationConfig) -> &mut Self {
+        self.commands.add(AddStyleTween {
+            animation: self.animation,
+            to,
+            config,
+            velocity: 0.,
+        });
+        self
+    }
+}
+
+impl<'a> UiStyle<'a> {
+    pub fn animate(&mut self) -> UiStyleAnimation<'_> {
+        let animation = StyleAnimationId::next();
+        let mut commands = self.commands.reborrow();
+        commands.add(StartStyleAnimation {
+            animation,
+            check_lock: true,
+            transition: false,
+        });
+
+        UiStyleAnimation {
+            animation,
+            commands,
+        }
+    }
+}
+
+impl<'a> UiStyleUnchecked<'a> {
+    pub fn animate(&mut self) -> UiStyleAnimation<'_> {
+        let animation = StyleAnimationId::next();
+        let mut commands = self.commands.reborrow();
+        commands.add(StartStyleAnimation {
+            animation,
+            check_lock: false,
+            transition: false,
+        });
+
+        UiStyleAnimation {
+            animation,
+            commands,
+        }
+    }
+}
@@ -3,6 +3,7 @@ pub mod column;
 pub mod container;
 pub mod context_menu;
 pub mod docking_zone;
+pub mod drag_value;
 pub mod dropdown;
 pub mod floating_panel;
 pub mod foldable;
@@ -18,6 +19,8 @@ pub mod sized_zone;
 pub mod slider;
 pub mod submenu;
 pub mod tab_container;
+pub mod text_area;
+pub mod text_input;
 pub mod toggle_menu_item;
 
 use bevy::prelude::*;
@@ -26,28 +29,33 @@ use self::{
     checkbox::CheckboxPlugin,
     context_menu::ContextMenuPlugin,
     docking_zone::DockingZonePlugin,
+    drag_value::DragValuePlugin,
     dropdown::DropdownPlugin,
     floating_panel::{FloatingPanelPlugin, FloatingPanelUpdate},
     foldable::FoldablePlugin,
     menu::MenuPlugin,
     menu_item::MenuItemPlugin,
+    panel::PanelPlugin,
     radio_group::RadioGroupPlugin,
     scroll_view::ScrollViewPlugin,
     sized_zone::SizedZonePlugin,
     slider::SliderPlugin,
     submenu::SubmenuPlugin,
     tab_container::TabContainerPlugin,
+    text_area::TextAreaPlugin,
+    text_input::TextInputPlugin,
     toggle_menu_item::ToggleMenuItemPlugin,
 };
 
 pub mod prelude {
     pub use super::{
-        checkbox::{Checkbox, UiCheckboxExt},
+        checkbox::{Checkbox, CheckboxToggled, UiCheckboxExt},
         column::UiColumnExt,
         container::UiContainerExt,
         context_menu::{ContextMenuGenerator, GenerateContextMenu, ReflectContextMenuGenerator},
         docking_zone::UiDockingZoneExt,
-        dropdown::UiDropdownExt,
+        drag_value::{DragValue, DragValueChanged, DragValueConfig, DragValueMode, UiDragValueExt},
+        dropdown::{DropdownSelected, UiDropdownExt},
         floating_panel::{FloatingPanelConfig, FloatingPanelLayout, UiFloatingPanelExt},
         foldable::UiFoldableExt,
         icon::UiIconExt,
@@ -56,15 +64,22 @@ pub mod prelude {
             MenuConfig, MenuItemSeparator, MenuSeparator, UiMenuExt, UiMenuItemSeparatorExt,
             UiMenuSeparatorExt,
         },
-        menu_item::{MenuItem, MenuItemConfig, MenuItemUpdate, UiMenuItemExt},
-        panel::UiPanelExt,
-        radio_group::{RadioGroup, UiRadioGroupExt},
+        menu_item::{MenuItem, MenuItemActivated, MenuItemConfig, MenuItemUpdate, UiMenuItemExt},
+        panel::{PanelClosed, UiPanelExt},
+        radio_group::{RadioGroup, RadioSelected, UiRadioGroupExt},
         row::UiRowExt,
         scroll_view::{ScrollThrough, UiScrollViewExt},
         sized_zone::{SizedZoneConfig, UiSizedZoneExt},
-        slider::{SliderConfig, UiSliderExt},
+        slider::{
+            SliderBarClick, SliderChanged, SliderConfig, SliderFormat, SliderRangeChanged,
+            SliderScale, UiSliderExt,
+        },
         submenu::{SubmenuConfig, UiSubmenuExt},
-        tab_container::UiTabContainerExt,
+        tab_container::{TabActivated, UiTabContainerExt},
+        text_area::{TextArea, TextAreaChanged, TextAreaConfig, UiTextAreaExt},
+        text_input::{
+            TextInput, TextInputChanged, TextInputConfig, TextInputSubmitted, UiTextInputExt,
+        },
         toggle_menu_item::{ToggleMenuItem, ToggleMenuItemConfig, UiToggleMenuItemExt},
     };
 }
@@ -88,7 +103,13 @@ impl Plugin for WidgetsPlugin {
                 SliderPlugin,
                 ScrollViewPlugin,
                 SubmenuPlugin,
+            ))
+            .add_plugins((
+                DragValuePlugin,
+                PanelPlugin,
                 TabContainerPlugin,
+                TextAreaPlugin,
+                TextInputPlugin,
                 ToggleMenuItemPlugin,
             ));
     }
@@ -3,8 +3,17 @@ use sickle_math::ease::Ease;
 
 use crate::{
     animated_interaction::{AnimatedInteraction, AnimationConfig},
-    interactions::InteractiveBackground,
+    focus_interaction::Focusable,
+    interactions::{set_pseudo_state, InteractiveBackground, PseudoState, PseudoStates},
+    theme::{
+        tokens::{ColorToken, SpacingToken, TokenRect, TokenStyle},
+        ComponentThemePlugin,
+    },
     ui_builder::UiBuilder,
+    ui_callbacks::{
+        report_ui_callbacks, UiCallbackEvent, UiCallbackTrigger, UiCallbackTriggered,
+        UiCallbackUpdate,
+    },
     ui_style::{SetEntityVisiblityExt, SetImageExt, UiStyleExt},
     FluxInteraction, TrackedInteraction,
 };
@@ -20,22 +29,57 @@ pub struct CheckboxPlugin;
 
 impl Plugin for CheckboxPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (toggle_checkbox, update_checkbox).chain());
+        app.add_plugins(ComponentThemePlugin::<Checkbox>::default())
+            .add_event::<CheckboxToggled>()
+            .add_event::<UiCallbackTriggered>()
+            .add_systems(
+                Update,
+                report_ui_callbacks::<CheckboxToggled>
+                    .after(toggle_checkbox)
+                    .before(UiCallbackUpdate),
+            )
+            .add_systems(Update, (toggle_checkbox, update_checkbox).chain());
+    }
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CheckboxToggled {
+    pub entity: Entity,
+    pub checked: bool,
+}
+
+impl UiCallbackEvent for CheckboxToggled {
+    const TRIGGER: UiCallbackTrigger = UiCallbackTrigger::Change;
+
+    fn entity(&self) -> Entity {
+        self.entity
     }
 }
 
 fn toggle_checkbox(
-    mut q_checkboxes: Query<(&mut Checkbox, &FluxInteraction), Changed<FluxInteraction>>,
+    mut q_checkboxes: Query<(Entity, &mut Checkbox, &FluxInteraction), Changed<FluxInteraction>>,
+    mut e_toggled: EventWriter<CheckboxToggled>,
 ) {
-    for (mut checkbox, interaction) in &mut q_checkboxes {
+    for (entity, mut checkbox, interaction) in &mut q_checkboxes {
         if *interaction == FluxInteraction::Released {
             checkbox.checked = !checkbox.checked;
+            e_toggled.send(CheckboxToggled {
+                entity,
+                checked: checkbox.checked,
+            });
         }
     }
 }
 
-fn update_checkbox(q_checkboxes: Query<&Checkbox, Changed<Checkbox>>, mut commands: Commands) {
-    for checkbox in &q_checkboxes {
+fn update_checkbox(
+    mut q_checkboxes: Query<(&Checkbox, Option<&mut PseudoStates>), Changed<Checkbox>>,
+    mut commands: Commands,
+) {
+    for (checkbox, pseudo_states) in &mut q_checkboxes {
+        if let Some(mut pseudo_states) = pseudo_states {
+            set_pseudo_state(&mut pseudo_states, PseudoState::Checked, checkbox.checked);
+        }
+
         commands
             .style(checkbox.check_node)
             .visibility(match checkbox.checked {
@@ -84,8 +128,11 @@ impl Checkbox {
                 ..default()
             },
             TrackedInteraction::default(),
-            InteractiveBackground {
-                highlight: Color::rgba(0., 1., 1., 0.3).into(),
+            Focusable::default(),
+            PseudoStates::default(),
+            InteractiveBackground::default(),
+            TokenStyle {
+                highlight: ColorToken::Highlight.into(),
                 ..default()
             },
             AnimatedInteraction::<InteractiveBackground> {
@@ -106,11 +153,14 @@ impl Checkbox {
                     border: UiRect::all(Val::Px(1.)),
                     ..default()
                 },
-                border_color: Color::DARK_GRAY.into(),
-                background_color: Color::ANTIQUE_WHITE.into(),
                 focus_policy: FocusPolicy::Pass,
                 ..default()
             },
+            TokenStyle {
+                background: ColorToken::Input.into(),
+                border: ColorToken::Outline.into(),
+                ..default()
+            },
         )
     }
 
@@ -162,7 +212,7 @@ impl<'w, 's> UiCheckboxExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
 
                 container.label(LabelConfig {
                     label: label_string.clone(),
-                    margin: UiRect::right(Val::Px(10.)),
+                    spacing: TokenRect::right(SpacingToken::Medium).into(),
                     ..default()
                 });
             }
@@ -1,9 +1,12 @@
-use bevy::{ecs::system::CommandQueue, prelude::*, window::PrimaryWindow};
+use bevy::{ecs::system::CommandQueue, prelude::*};
 
 use crate::{
+    drag_interaction::{DragState, Draggable},
+    node_window::NodeWindow,
+    theme::tokens::{ColorToken, TokenStyle},
     ui_builder::{UiBuilder, UiBuilderExt, UiContextRoot},
     ui_style::{SetAbsolutePositionExt, UiStyleExt},
-    FluxInteractionUpdate,
+    FluxInteractionUpdate, LongPressed,
 };
 
 use super::prelude::UiMenuItemSeparatorExt;
@@ -32,26 +35,64 @@ impl Plugin for ContextMenuPlugin {
     }
 }
 
-// TODO: Handle long press on touch
 fn handle_click_or_touch(
     r_mouse: Res<ButtonInput<MouseButton>>,
+    r_touches: Res<Touches>,
+    mut e_long_pressed: EventReader<LongPressed>,
     q_context_menu: Query<&Interaction, (With<ContextMenu>, Changed<Interaction>)>,
-    mut q_interacted: Query<(Entity, &Interaction, &mut GenerateContextMenu)>,
+    mut q_interacted: Query<(
+        Entity,
+        &Interaction,
+        &mut GenerateContextMenu,
+        Option<&Draggable>,
+    )>,
     mut commands: Commands,
+    mut opening_touch: Local<Option<u64>>,
 ) {
     let mut close_all = false;
 
-    if r_mouse.just_pressed(MouseButton::Right) {
-        let mut open: Option<Entity> = None;
-        for (entity, interaction, _) in &q_interacted {
-            if *interaction == Interaction::Hovered {
-                open = entity.into();
-                break;
+    // Long press opens the context menu on touch, unless it turned into a drag
+    let long_pressed = e_long_pressed
+        .read()
+        .map(|long_pressed| long_pressed.entity)
+        .filter(|entity| match q_interacted.get(*entity) {
+            Ok((_, _, _, Some(draggable))) => {
+                draggable.state != DragState::DragStart && draggable.state != DragState::Dragging
+            }
+            Ok(_) => true,
+            Err(_) => false,
+        })
+        .last();
+    let touch = r_touches.iter().next().map(|touch| touch.id());
+    let touch_long_pressed = match (long_pressed, touch) {
+        (Some(entity), Some(_)) => entity.into(),
+        _ => None,
+    };
+
+    // The finger that opened the menu is lifted right after
+    let opening_touch_released = match *opening_touch {
+        Some(id) => r_touches.just_released(id),
+        None => false,
+    };
+    if opening_touch_released {
+        *opening_touch = None;
+    }
+
+    if r_mouse.just_pressed(MouseButton::Right) || touch_long_pressed.is_some() {
+        let mut open: Option<Entity> = touch_long_pressed;
+        if open.is_some() {
+            *opening_touch = touch;
+        } else {
+            for (entity, interaction, _, _) in &q_interacted {
+                if *interaction == Interaction::Hovered {
+                    open = entity.into();
+                    break;
+                }
             }
         }
 
         if let Some(open) = open {
-            for (entity, _, mut gen_menu) in &mut q_interacted {
+            for (entity, _, mut gen_menu, _) in &mut q_interacted {
                 if entity == open {
                     if !gen_menu.is_open {
                         gen_menu.is_open = true;
@@ -66,7 +107,9 @@ fn handle_click_or_touch(
         } else {
             close_all = true;
         }
-    } else if r_mouse.any_just_pressed([MouseButton::Left, MouseButton::Middle]) {
+    } else if r_mouse.any_just_pressed([MouseButton::Left, MouseButton::Middle])
+        || r_touches.any_just_pressed()
+    {
         let mut on_context_menu = false;
         for interaction in &q_context_menu {
             if *interaction == Interaction::Pressed {
@@ -77,12 +120,14 @@ fn handle_click_or_touch(
         if !on_context_menu {
             close_all = true;
         }
-    } else if r_mouse.any_just_released([MouseButton::Left, MouseButton::Middle]) {
+    } else if r_mouse.any_just_released([MouseButton::Left, MouseButton::Middle])
+        || (r_touches.any_just_released() && !opening_touch_released)
+    {
         close_all = true;
     }
 
     if close_all {
-        for (_, _, mut gen_menu) in &mut q_interacted {
+        for (_, _, mut gen_menu, _) in &mut q_interacted {
             if gen_menu.is_open {
                 gen_menu.is_open = false;
             }
@@ -225,44 +270,50 @@ fn generate_context_menu(world: &mut World) {
     }
 }
 
-// TODO: Handle touch position
 fn position_added_context_menu(
     q_context_menus: Query<Entity, Added<ContextMenu>>,
-    q_window: Query<&Window, With<PrimaryWindow>>,
-    //r_touches: Res<Touches>,
+    node_window: NodeWindow,
+    q_window: Query<&Window>,
+    r_touches: Res<Touches>,
     mut commands: Commands,
 ) {
-    let Ok(window) = q_window.get_single() else {
-        return;
-    };
+    for entity in &q_context_menus {
+        let Some(window) = node_window
+            .window(entity)
+            .and_then(|window| q_window.get(window).ok())
+        else {
+            continue;
+        };
 
-    let position = window.cursor_position();
-    // if position.is_none() {
-    //     position = r_touches.first_pressed_position();
-    // }
+        let position = window
+            .cursor_position()
+            .or_else(|| r_touches.first_pressed_position());
 
-    let Some(position) = position else {
-        return;
-    };
+        let Some(position) = position else {
+            continue;
+        };
 
-    for entity in &q_context_menus {
         commands.style(entity).absolute_position(position);
     }
 }
 
 fn update_context_menu_vertical_position(
     mut q_node_style: Query<
-        (&Node, &Transform, &mut Style, &mut Visibility),
+        (Entity, &Node, &Transform, &mut Style, &mut Visibility),
         (With<ContextMenu>, Changed<Node>),
     >,
-    q_window: Query<&Window, With<PrimaryWindow>>,
+    node_window: NodeWindow,
+    q_window: Query<&Window>,
 ) {
-    let Ok(window) = q_window.get_single() else {
-        return;
-    };
-
-    let resolution = Vec2::new(window.resolution.width(), window.resolution.height());
-    for (node, transform, mut style, mut visibility) in &mut q_node_style {
+    for (entity, node, transform, mut style, mut visibility) in &mut q_node_style {
+        let Some(window) = node_window
+            .window(entity)
+            .and_then(|window| q_window.get(window).ok())
+        else {
+            continue;
+        };
+
+        let resolution = Vec2::new(window.resolution.width(), window.resolution.height());
         let size = node.size();
 
         let position = transform.translation.truncate() - (size / 2.);
@@ -342,13 +393,16 @@ impl ContextMenu {
                     ..default()
                 },
                 z_index: ZIndex::Global(MENU_CONTAINER_Z_INDEX),
-                background_color: Color::rgb(0.1, 0.1, 0.1).into(),
-                border_color: Color::ANTIQUE_WHITE.into(),
                 focus_policy: bevy::ui::FocusPolicy::Block,
                 visibility: Visibility::Hidden,
                 ..default()
             },
             Interaction::default(),
+            TokenStyle {
+                background: ColorToken::Surface.into(),
+                border: ColorToken::OnSurface.into(),
+                ..default()
+            },
         )
     }
 }
@@ -8,6 +8,7 @@ use crate::{
     drag_interaction::{DragState, Draggable},
     drop_interaction::{DropPhase, DropZone, DroppableUpdate},
     hierarchy_delay::DelayActions,
+    theme::tokens::{ColorToken, ThemeTokens},
     ui_builder::{UiBuilder, UiBuilderExt},
     ui_commands::ResetChildrenInUiSurface,
     ui_style::{
@@ -424,6 +425,7 @@ fn handle_docking_zone_drop_zone_change(
     q_accepted_query: Query<&FloatingPanelTitle>,
     q_tab_container: Query<&TabContainer>,
     q_tab_bar: Query<(&Node, &Interaction), With<TabBar>>,
+    tokens: Res<ThemeTokens>,
     mut commands: Commands,
 ) {
     for (entity, docking_zone, drop_zone, node, transform) in &q_docking_zones {
@@ -490,7 +492,7 @@ fn handle_docking_zone_drop_zone_change(
                 .height(height)
                 .left(left)
                 .top(top)
-                .background_color(Color::rgba(0.7, 0.8, 0.9, 0.2));
+                .background_color(tokens.color(ColorToken::Highlight));
         } else if drop_zone.drop_phase() == DropPhase::Dropped {
             // Validated above
             let droppable_title = q_accepted_query
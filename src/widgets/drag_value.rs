@@ -0,0 +1,665 @@
+use bevy::{prelude::*, ui::FocusPolicy, window::CursorIcon};
+use sickle_math::ease::Ease;
+
+use crate::{
+    animated_interaction::{AnimatedInteraction, AnimationConfig},
+    drag_interaction::{DragState, Draggable, DraggableUpdate},
+    focus_interaction::{Focusable, FocusedEntity},
+    input_extension::SymmetricKeysExt,
+    interactions::InteractiveBackground,
+    theme::{
+        tokens::{ColorToken, FontSizeToken, SpacingToken, ThemeTokens, TokenRect, TokenStyle},
+        ComponentThemePlugin, LockedStyleAttributes, StylableAttribute,
+    },
+    ui_builder::UiBuilder,
+    ui_callbacks::{
+        report_ui_callbacks, UiCallbackEvent, UiCallbackTrigger, UiCallbackTriggered,
+        UiCallbackUpdate,
+    },
+    ui_commands::SetCursorExt,
+    ui_style::{
+        SetNodeBorderExt, SetNodeFlexGrowExt, SetNodeHeightExt, SetNodeMarginExt,
+        SetNodeShowHideExt, SetNodeWidthExt, UiStyleExt,
+    },
+    Clicked, FluxInteraction, PressAndHoldRepeat, TrackedInteraction,
+};
+
+use super::{
+    prelude::{
+        LabelConfig, TextInput, TextInputConfig, TextInputSubmitted, UiContainerExt, UiIconExt,
+        UiLabelExt, UiTextInputExt,
+    },
+    text_input::TextInputUpdate,
+};
+
+/// Slows dragging down while Shift is held
+const FINE_DRAG_FACTOR: f32 = 0.1;
+
+pub struct DragValuePlugin;
+
+impl Plugin for DragValuePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(ComponentThemePlugin::<DragValue>::default())
+            .register_type::<DragValue>()
+            .register_type::<DragValueField>()
+            .register_type::<DragValueStepButton>()
+            .add_event::<DragValueChanged>()
+            .add_event::<UiCallbackTriggered>()
+            .add_systems(
+                Update,
+                report_ui_callbacks::<DragValueChanged>
+                    .after(update_drag_value_readout)
+                    .before(UiCallbackUpdate),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_drag_value_cursor,
+                    update_drag_value_on_drag.after(DraggableUpdate),
+                    update_drag_value_on_step,
+                    update_drag_value_on_click,
+                    update_drag_value_on_submit.after(TextInputUpdate),
+                    update_drag_value_readout,
+                )
+                    .chain(),
+            );
+    }
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DragValueChanged {
+    pub entity: Entity,
+    pub value: f32,
+}
+
+impl UiCallbackEvent for DragValueChanged {
+    const TRIGGER: UiCallbackTrigger = UiCallbackTrigger::Change;
+
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+fn update_drag_value_cursor(
+    q_fields: Query<(Entity, Ref<FluxInteraction>), With<DragValueField>>,
+    mut commands: Commands,
+) {
+    for (entity, flux_interaction) in &q_fields {
+        if !flux_interaction.is_changed() {
+            continue;
+        }
+
+        let cursor = match *flux_interaction {
+            FluxInteraction::PointerEnter
+            | FluxInteraction::Pressed
+            | FluxInteraction::Released => CursorIcon::EwResize,
+            FluxInteraction::PointerLeave | FluxInteraction::PressCanceled => CursorIcon::Default,
+            _ => continue,
+        };
+
+        commands.entity(entity).set_cursor(cursor);
+    }
+}
+
+fn update_drag_value_on_drag(
+    q_draggable: Query<(&Draggable, &DragValueField), Changed<Draggable>>,
+    mut q_drag_value: Query<&mut DragValue>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut e_changed: EventWriter<DragValueChanged>,
+) {
+    for (draggable, field) in &q_draggable {
+        let Ok(mut drag_value) = q_drag_value.get_mut(field.drag_value) else {
+            continue;
+        };
+
+        match draggable.state {
+            DragState::Inactive | DragState::MaybeDragged => continue,
+            DragState::DragCanceled => {
+                if let Some(base_value) = drag_value.base_value.take() {
+                    drag_value.value = base_value;
+                    e_changed.send(DragValueChanged {
+                        entity: field.drag_value,
+                        value: base_value,
+                    });
+                }
+                continue;
+            }
+            DragState::DragEnd => {
+                drag_value.base_value = None;
+                continue;
+            }
+            DragState::DragStart => {
+                drag_value.base_value = drag_value.value.into();
+                drag_value.unsnapped_value = drag_value.value;
+            }
+            DragState::Dragging => (),
+        }
+
+        let Some(diff) = draggable.diff else {
+            continue;
+        };
+        if diff.x == 0. {
+            continue;
+        }
+
+        let factor = match keys.symmetry_pressed(KeyCode::ShiftLeft) {
+            true => FINE_DRAG_FACTOR,
+            false => 1.,
+        };
+        let config = &drag_value.config;
+        let unsnapped_value = (drag_value.unsnapped_value + diff.x * config.step * factor)
+            .clamp(config.min, config.max);
+        let value = config.snap(unsnapped_value);
+
+        drag_value.unsnapped_value = unsnapped_value;
+        if value != drag_value.value {
+            drag_value.value = value;
+            e_changed.send(DragValueChanged {
+                entity: field.drag_value,
+                value,
+            });
+        }
+    }
+}
+
+fn update_drag_value_on_step(
+    q_buttons: Query<(Entity, &DragValueStepButton, Ref<FluxInteraction>)>,
+    mut e_repeat: EventReader<PressAndHoldRepeat>,
+    mut q_drag_value: Query<&mut DragValue>,
+    mut e_changed: EventWriter<DragValueChanged>,
+) {
+    let repeated: Vec<Entity> = e_repeat.read().map(|event| event.entity).collect();
+
+    for (entity, button, flux_interaction) in &q_buttons {
+        let pressed =
+            flux_interaction.is_changed() && *flux_interaction == FluxInteraction::Pressed;
+        if !pressed && !repeated.contains(&entity) {
+            continue;
+        }
+
+        let Ok(mut drag_value) = q_drag_value.get_mut(button.drag_value) else {
+            continue;
+        };
+
+        let config = &drag_value.config;
+        let value = config
+            .snap(drag_value.value + button.direction * config.step)
+            .clamp(config.min, config.max);
+        if value != drag_value.value {
+            drag_value.value = value;
+            e_changed.send(DragValueChanged {
+                entity: button.drag_value,
+                value,
+            });
+        }
+    }
+}
+
+fn update_drag_value_on_click(
+    mut e_clicked: EventReader<Clicked>,
+    q_fields: Query<(&DragValueField, &Draggable)>,
+    mut q_drag_value: Query<&mut DragValue>,
+    mut q_text_input: Query<&mut TextInput>,
+    mut focused: ResMut<FocusedEntity>,
+    mut commands: Commands,
+) {
+    for event in e_clicked.read() {
+        // Releasing a drag is not a click on the value
+        let Ok((field, draggable)) = q_fields.get(event.entity) else {
+            continue;
+        };
+        if draggable.state == DragState::DragEnd {
+            continue;
+        }
+
+        let Ok(mut drag_value) = q_drag_value.get_mut(field.drag_value) else {
+            continue;
+        };
+        let Ok(mut text_input) = q_text_input.get_mut(drag_value.input) else {
+            continue;
+        };
+
+        text_input.set_value(drag_value.config.format(drag_value.value, false));
+        text_input.select_all();
+        drag_value.editing = true;
+        focused.0 = drag_value.input.into();
+
+        commands
+            .entity(event.entity)
+            .set_cursor(CursorIcon::Default);
+        commands.style(drag_value.field).render(false);
+        commands.style(drag_value.input).render(true);
+    }
+}
+
+/// Applies the typed value on submit, cancels editing once the input loses focus
+fn update_drag_value_on_submit(
+    mut e_submitted: EventReader<TextInputSubmitted>,
+    mut q_drag_value: Query<(Entity, &mut DragValue)>,
+    mut focused: ResMut<FocusedEntity>,
+    mut e_changed: EventWriter<DragValueChanged>,
+    mut commands: Commands,
+) {
+    let submitted: Vec<&TextInputSubmitted> = e_submitted.read().collect();
+
+    for (entity, mut drag_value) in &mut q_drag_value {
+        if !drag_value.editing {
+            continue;
+        }
+
+        let submit = submitted
+            .iter()
+            .find(|event| event.entity == drag_value.input);
+        if submit.is_none() && focused.0 == Some(drag_value.input) {
+            continue;
+        }
+
+        if let Some(submit) = submit {
+            match drag_value.config.parse(&submit.value) {
+                Some(value) if value != drag_value.value => {
+                    drag_value.value = value;
+                    e_changed.send(DragValueChanged { entity, value });
+                }
+                Some(_) => (),
+                None => warn!(
+                    "Failed to set value of {:?}: {:?} is not a number",
+                    entity, submit.value
+                ),
+            }
+
+            focused.0 = drag_value.field.into();
+        }
+
+        drag_value.editing = false;
+        commands.style(drag_value.input).render(false);
+        commands.style(drag_value.field).render(true);
+    }
+}
+
+fn update_drag_value_readout(
+    q_drag_value: Query<&DragValue, Changed<DragValue>>,
+    mut q_text: Query<&mut Text>,
+    tokens: Res<ThemeTokens>,
+) {
+    for drag_value in &q_drag_value {
+        let Ok(mut text) = q_text.get_mut(drag_value.readout) else {
+            continue;
+        };
+
+        let content = drag_value.config.format(drag_value.value, true);
+        if text.sections.len() == 1 && text.sections[0].value == content {
+            continue;
+        }
+
+        text.sections = vec![TextSection::new(
+            content,
+            tokens.text_style(ColorToken::OnInput, FontSizeToken::Medium),
+        )];
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Reflect)]
+pub enum DragValueMode {
+    Integer,
+    #[default]
+    Float,
+}
+
+#[derive(Clone, Debug, Reflect)]
+pub struct DragValueConfig {
+    pub label: Option<String>,
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    /// Change of the value per pixel dragged and per step button press
+    pub step: f32,
+    /// Decimals of float values
+    pub precision: usize,
+    /// Unit appended to the value, i.e. `" px"` or `"°"`
+    pub suffix: String,
+    pub mode: DragValueMode,
+}
+
+impl Default for DragValueConfig {
+    fn default() -> Self {
+        Self {
+            label: None,
+            value: 0.,
+            min: f32::MIN,
+            max: f32::MAX,
+            step: 0.1,
+            precision: 2,
+            suffix: String::new(),
+            mode: DragValueMode::Float,
+        }
+    }
+}
+
+impl DragValueConfig {
+    pub fn float(value: f32) -> Self {
+        Self { value, ..default() }
+    }
+
+    pub fn integer(value: i32) -> Self {
+        Self {
+            value: value as f32,
+            step: 1.,
+            precision: 0,
+            mode: DragValueMode::Integer,
+            ..default()
+        }
+    }
+
+    pub fn with_label(self, label: impl Into<String>) -> Self {
+        Self {
+            label: label.into().into(),
+            ..self
+        }
+    }
+
+    pub fn with_range(self, min: f32, max: f32) -> Self {
+        if max < min {
+            panic!("Invalid drag value range! Min: {}, Max: {}", min, max);
+        }
+
+        Self { min, max, ..self }
+    }
+
+    pub fn with_step(self, step: f32) -> Self {
+        Self { step, ..self }
+    }
+
+    pub fn with_precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+
+    pub fn with_suffix(self, suffix: impl Into<String>) -> Self {
+        Self {
+            suffix: suffix.into(),
+            ..self
+        }
+    }
+
+    /// Rounds the value to the precision of the mode
+    pub fn snap(&self, value: f32) -> f32 {
+        match self.mode {
+            DragValueMode::Integer => value.round(),
+            DragValueMode::Float => {
+                let scale = 10_f32.powi(self.precision as i32);
+                (value * scale).round() / scale
+            }
+        }
+    }
+
+    pub fn format(&self, value: f32, with_suffix: bool) -> String {
+        let precision = match self.mode {
+            DragValueMode::Integer => 0,
+            DragValueMode::Float => self.precision,
+        };
+
+        match with_suffix {
+            true => format!("{:.*}{}", precision, value, self.suffix),
+            false => format!("{:.*}", precision, value),
+        }
+    }
+
+    /// Parses a typed value, with or without the suffix
+    pub fn parse(&self, text: &str) -> Option<f32> {
+        let text = text.trim();
+        let suffix = self.suffix.trim();
+        let text = match suffix.is_empty() {
+            true => text,
+            false => text.strip_suffix(suffix).unwrap_or(text).trim_end(),
+        };
+
+        text.parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .map(|value| self.snap(value).clamp(self.min, self.max))
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DragValue {
+    pub config: DragValueConfig,
+    value: f32,
+    /// Value before snapping, so slow drags still add up
+    unsnapped_value: f32,
+    base_value: Option<f32>,
+    editing: bool,
+    field: Entity,
+    readout: Entity,
+    input: Entity,
+}
+
+impl Default for DragValue {
+    fn default() -> Self {
+        Self {
+            config: Default::default(),
+            value: 0.,
+            unsnapped_value: 0.,
+            base_value: None,
+            editing: false,
+            field: Entity::PLACEHOLDER,
+            readout: Entity::PLACEHOLDER,
+            input: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl DragValue {
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Sets the value, snapped and clamped to the config. Does not send [`DragValueChanged`].
+    pub fn set_value(&mut self, value: f32) {
+        self.value = self
+            .config
+            .snap(value)
+            .clamp(self.config.min, self.config.max);
+    }
+
+    fn base_tween() -> AnimationConfig {
+        AnimationConfig {
+            duration: 0.1,
+            easing: Ease::OutExpo,
+            ..default()
+        }
+    }
+
+    fn container() -> impl Bundle {
+        (
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            TokenStyle {
+                margin: TokenRect::all(SpacingToken::Small).into(),
+                ..default()
+            },
+        )
+    }
+
+    fn frame() -> impl Bundle {
+        (
+            Name::new("Drag Value"),
+            NodeBundle {
+                style: Style {
+                    flex_grow: 1.,
+                    height: Val::Px(26.),
+                    border: UiRect::all(Val::Px(1.)),
+                    align_items: AlignItems::Stretch,
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                ..default()
+            },
+            TokenStyle {
+                background: ColorToken::Input.into(),
+                border: ColorToken::Outline.into(),
+                ..default()
+            },
+        )
+    }
+
+    fn field(drag_value: Entity) -> impl Bundle {
+        (
+            Name::new("Field"),
+            NodeBundle {
+                style: Style {
+                    flex_grow: 1.,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                focus_policy: FocusPolicy::Block,
+                ..default()
+            },
+            Interaction::default(),
+            TrackedInteraction::default(),
+            Focusable::default(),
+            Draggable::default(),
+            DragValueField { drag_value },
+        )
+    }
+
+    fn step_button(drag_value: Entity, direction: f32) -> impl Bundle {
+        (
+            Name::new(match direction < 0. {
+                true => "Decrement",
+                false => "Increment",
+            }),
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(20.),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                focus_policy: FocusPolicy::Block,
+                ..default()
+            },
+            Interaction::default(),
+            TrackedInteraction::default(),
+            InteractiveBackground::default(),
+            TokenStyle {
+                highlight: ColorToken::Highlight.into(),
+                ..default()
+            },
+            AnimatedInteraction::<InteractiveBackground> {
+                tween: DragValue::base_tween(),
+                ..default()
+            },
+            DragValueStepButton {
+                drag_value,
+                direction,
+            },
+        )
+    }
+}
+
+/// The draggable part of a [`DragValue`], click it to type a value
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DragValueField {
+    pub drag_value: Entity,
+}
+
+impl Default for DragValueField {
+    fn default() -> Self {
+        Self {
+            drag_value: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DragValueStepButton {
+    pub drag_value: Entity,
+    /// -1 to decrement, 1 to increment
+    pub direction: f32,
+}
+
+impl Default for DragValueStepButton {
+    fn default() -> Self {
+        Self {
+            drag_value: Entity::PLACEHOLDER,
+            direction: 1.,
+        }
+    }
+}
+
+pub trait UiDragValueExt<'w, 's> {
+    fn drag_value<'a>(&'a mut self, config: DragValueConfig) -> UiBuilder<'w, 's, 'a, Entity>;
+}
+
+impl<'w, 's> UiDragValueExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
+    fn drag_value<'a>(&'a mut self, config: DragValueConfig) -> UiBuilder<'w, 's, 'a, Entity> {
+        let mut field = Entity::PLACEHOLDER;
+        let mut readout = Entity::PLACEHOLDER;
+        let mut input = Entity::PLACEHOLDER;
+
+        let mut drag_value = self.container(DragValue::container(), |container| {
+            let drag_value_id = container.id();
+
+            if let Some(label) = config.label.clone() {
+                container.insert(Name::new(format!("Drag Value [{}]", label)));
+                container.label(LabelConfig {
+                    label,
+                    spacing: TokenRect::right(SpacingToken::Medium).into(),
+                    ..default()
+                });
+            }
+
+            container.container(DragValue::frame(), |frame| {
+                frame.container(DragValue::step_button(drag_value_id, -1.), |button| {
+                    button.icon("embedded://sickle_ui/icons/chevron_left.png");
+                });
+
+                field = frame
+                    .container(DragValue::field(drag_value_id), |field| {
+                        readout = field.label(LabelConfig::default()).id();
+                    })
+                    .id();
+
+                let mut text_input = frame.text_input(TextInputConfig::default());
+                text_input
+                    .style()
+                    .width(Val::Auto)
+                    .height(Val::Auto)
+                    .margin(UiRect::all(Val::Px(0.)))
+                    .border(UiRect::all(Val::Px(0.)))
+                    .flex_grow(1.)
+                    .render(false);
+                // The frame spaces the field, not the tokens
+                text_input.insert(LockedStyleAttributes::from_vec(vec![
+                    StylableAttribute::Margin,
+                ]));
+                input = text_input.id();
+
+                frame.container(DragValue::step_button(drag_value_id, 1.), |button| {
+                    button.icon("embedded://sickle_ui/icons/chevron_right.png");
+                });
+            });
+        });
+
+        let mut value = DragValue {
+            config,
+            field,
+            readout,
+            input,
+            ..default()
+        };
+        value.set_value(value.config.value);
+        drag_value.insert(value);
+
+        drag_value
+    }
+}
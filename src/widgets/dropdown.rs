@@ -5,9 +5,18 @@ use sickle_math::ease::Ease;
 
 use crate::{
     animated_interaction::{AnimatedInteraction, AnimationConfig},
-    interactions::InteractiveBackground,
+    focus_interaction::Focusable,
+    interactions::{InteractiveBackground, PseudoStates},
     scroll_interaction::{ScrollAxis, Scrollable},
+    theme::{
+        tokens::{ColorToken, SpacingToken, TokenRect, TokenStyle},
+        ComponentThemePlugin,
+    },
     ui_builder::UiBuilder,
+    ui_callbacks::{
+        report_ui_callbacks, UiCallbackEvent, UiCallbackTrigger, UiCallbackTriggered,
+        UiCallbackUpdate,
+    },
     FluxInteraction, FluxInteractionUpdate, TrackedInteraction,
 };
 
@@ -24,7 +33,19 @@ pub struct DropdownPlugin;
 
 impl Plugin for DropdownPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.add_plugins((
+            ComponentThemePlugin::<Dropdown>::default(),
+            ComponentThemePlugin::<DropdownOption>::default(),
+        ))
+        .add_event::<DropdownSelected>()
+        .add_event::<UiCallbackTriggered>()
+        .add_systems(
+            Update,
+            report_ui_callbacks::<DropdownSelected>
+                .after(handle_option_press)
+                .before(UiCallbackUpdate),
+        )
+        .add_systems(
             Update,
             (
                 handle_option_press,
@@ -37,6 +58,20 @@ impl Plugin for DropdownPlugin {
     }
 }
 
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DropdownSelected {
+    pub entity: Entity,
+    pub value: usize,
+}
+
+impl UiCallbackEvent for DropdownSelected {
+    const TRIGGER: UiCallbackTrigger = UiCallbackTrigger::Change;
+
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
 fn update_dropdown_label(
     mut q_dropdowns: Query<(&mut Dropdown, &DropdownOptions), Changed<Dropdown>>,
     mut q_text: Query<&mut Text>,
@@ -65,10 +100,19 @@ fn update_dropdown_label(
 fn handle_click_or_touch(
     r_mouse: Res<ButtonInput<MouseButton>>,
     r_touches: Res<Touches>,
-    mut q_dropdowns: Query<(Entity, &mut Dropdown, &FluxInteraction)>,
+    q_options: Query<&FluxInteraction, (With<DropdownOption>, Changed<FluxInteraction>)>,
+    mut q_dropdowns: Query<(Entity, &mut Dropdown, Ref<FluxInteraction>)>,
 ) {
+    // Keyboard activation releases without a pointer release
+    let key_released = q_dropdowns.iter().any(|(_, _, interaction)| {
+        interaction.is_changed() && *interaction == FluxInteraction::Released
+    }) || q_options
+        .iter()
+        .any(|interaction| *interaction == FluxInteraction::Released);
+
     if r_mouse.any_just_released([MouseButton::Left, MouseButton::Middle, MouseButton::Right])
         || r_touches.any_just_released()
+        || key_released
     {
         let mut open: Option<Entity> = None;
         for (entity, _, interaction) in &mut q_dropdowns {
@@ -95,6 +139,7 @@ fn handle_click_or_touch(
 fn handle_option_press(
     q_options: Query<(&DropdownOption, &FluxInteraction), Changed<FluxInteraction>>,
     mut q_dropdown: Query<&mut Dropdown>,
+    mut e_selected: EventWriter<DropdownSelected>,
 ) {
     for (option, interaction) in &q_options {
         if *interaction == FluxInteraction::Released {
@@ -103,6 +148,10 @@ fn handle_option_press(
             };
 
             dropdown.value = option.option.into();
+            e_selected.send(DropdownSelected {
+                entity: option.dropdown,
+                value: option.option,
+            });
         }
     }
 }
@@ -211,12 +260,15 @@ impl Dropdown {
                     padding: UiRect::horizontal(Val::Px(5.)),
                     ..default()
                 },
-                background_color: Color::GRAY.into(),
                 ..default()
             },
             TrackedInteraction::default(),
-            InteractiveBackground {
-                highlight: Color::rgba(0., 1., 1., 0.3).into(),
+            PseudoStates::default(),
+            Focusable::default(),
+            InteractiveBackground::default(),
+            TokenStyle {
+                background: ColorToken::SurfaceRaised.into(),
+                highlight: ColorToken::Highlight.into(),
                 ..default()
             },
             AnimatedInteraction::<InteractiveBackground> {
@@ -241,8 +293,11 @@ impl Dropdown {
                 ..default()
             },
             TrackedInteraction::default(),
-            InteractiveBackground {
-                highlight: Color::rgba(0., 1., 1., 0.3).into(),
+            PseudoStates::default(),
+            Focusable::default(),
+            InteractiveBackground::default(),
+            TokenStyle {
+                highlight: ColorToken::Highlight.into(),
                 ..default()
             },
             AnimatedInteraction::<InteractiveBackground> {
@@ -282,7 +337,7 @@ impl<'w, 's> UiDropdownExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
                 let dropdown_id = builder.id();
                 selected = builder
                     .label(LabelConfig {
-                        margin: UiRect::right(Val::Px(10.)),
+                        spacing: TokenRect::right(SpacingToken::Medium).into(),
                         ..default()
                     })
                     .id();
@@ -307,8 +362,8 @@ impl<'w, 's> UiDropdownExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
                                     |option| {
                                         option.label(LabelConfig {
                                             label: label.clone(),
-                                            margin: UiRect::horizontal(Val::Px(10.)),
-                                            color: Color::WHITE,
+                                            spacing: TokenRect::horizontal(SpacingToken::Medium)
+                                                .into(),
                                             ..default()
                                         });
                                     },
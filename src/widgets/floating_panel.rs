@@ -1,20 +1,22 @@
 use bevy::ui::FocusPolicy;
-use bevy::window::PrimaryWindow;
 use bevy::{prelude::*, window::WindowResized};
 use sickle_math::ease::Ease;
 
 use super::icon::UiIconExt;
+use super::panel::PanelClosed;
 use super::prelude::{LabelConfig, UiContainerExt, UiLabelExt, UiPanelExt};
 use super::prelude::{SetLabelTextExt, UiScrollViewExt};
 use crate::animated_interaction::{AnimatedInteraction, AnimationConfig};
 use crate::drop_interaction::{Droppable, DroppableUpdate};
 use crate::interactions::InteractiveBackground;
+use crate::node_window::NodeWindow;
 use crate::resize_interaction::ResizeHandle;
+use crate::theme::tokens::{ColorToken, TokenStyle};
 use crate::ui_builder::UiBuilderExt;
 use crate::ui_style::{
-    SetAbsolutePositionExt, SetBackgroundColorExt, SetEntityVisiblityExt, SetFluxInteractionExt,
-    SetFocusPolicyExt, SetImageExt, SetNodeFlexGrowExt, SetNodeHeightExt, SetNodeMarginExt,
-    SetNodeShowHideExt, SetNodeWidthExt, SetZIndexExt, UiStyleExt,
+    SetAbsolutePositionExt, SetEntityVisiblityExt, SetFluxInteractionExt, SetFocusPolicyExt,
+    SetImageExt, SetNodeFlexGrowExt, SetNodeHeightExt, SetNodeMarginExt, SetNodeShowHideExt,
+    SetNodeWidthExt, SetZIndexExt, UiStyleExt,
 };
 use crate::FluxInteraction;
 use crate::{
@@ -110,10 +112,18 @@ fn index_floating_panel(mut q_panels: Query<&mut FloatingPanel>) {
 
 fn process_panel_close_pressed(
     q_buttons: Query<(&FloatingPanelCloseButton, &FluxInteraction), Changed<FluxInteraction>>,
+    q_panel: Query<&FloatingPanel>,
+    mut e_closed: EventWriter<PanelClosed>,
     mut commands: Commands,
 ) {
     for (button, interaction) in &q_buttons {
         if *interaction == FluxInteraction::Released {
+            if let Ok(panel) = q_panel.get(button.panel) {
+                e_closed.send(PanelClosed {
+                    entity: panel.content_panel,
+                });
+            }
+
             commands.entity(button.panel).despawn_recursive();
         }
     }
@@ -300,16 +310,19 @@ fn window_resized(e_resized: EventReader<WindowResized>) -> bool {
     e_resized.len() > 0
 }
 
-// TODO: Use the panel's render window
 fn handle_window_resize(
-    q_window: Query<&Window, With<PrimaryWindow>>,
-    mut q_panels: Query<(&mut FloatingPanel, &Node, &GlobalTransform)>,
+    node_window: NodeWindow,
+    q_window: Query<&Window>,
+    mut q_panels: Query<(Entity, &mut FloatingPanel, &Node, &GlobalTransform)>,
 ) {
-    let Ok(window) = q_window.get_single() else {
-        return;
-    };
+    for (entity, mut panel, node, transform) in &mut q_panels {
+        let Some(window) = node_window
+            .window(entity)
+            .and_then(|window| q_window.get(window).ok())
+        else {
+            continue;
+        };
 
-    for (mut panel, node, transform) in &mut q_panels {
         let position = transform.translation().truncate() - (node.size() / 2.);
 
         if position.x > window.width() - WINDOW_RESIZE_PADDING {
@@ -592,20 +605,25 @@ impl FloatingPanel {
     }
 
     fn frame() -> impl Bundle {
-        NodeBundle {
-            style: Style {
-                position_type: PositionType::Absolute,
-                border: UiRect::all(Val::Px(2.)),
-                flex_direction: FlexDirection::Column,
-                align_items: AlignItems::Start,
-                overflow: Overflow::clip(),
+        (
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    border: UiRect::all(Val::Px(2.)),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Start,
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                focus_policy: bevy::ui::FocusPolicy::Block,
                 ..default()
             },
-            border_color: Color::BLACK.into(),
-            background_color: Color::rgb(0.15, 0.155, 0.16).into(),
-            focus_policy: bevy::ui::FocusPolicy::Block,
-            ..default()
-        }
+            TokenStyle {
+                background: ColorToken::SurfaceVariant.into(),
+                border: ColorToken::Outline.into(),
+                ..default()
+            },
+        )
     }
 
     fn title_container() -> impl Bundle {
@@ -618,7 +636,10 @@ impl FloatingPanel {
                     justify_content: JustifyContent::Start,
                     ..default()
                 },
-                background_color: Color::DARK_GRAY.into(),
+                ..default()
+            },
+            TokenStyle {
+                background: ColorToken::Header.into(),
                 ..default()
             },
         )
@@ -634,12 +655,15 @@ impl FloatingPanel {
                     border: UiRect::vertical(Val::Px(2.)),
                     ..default()
                 },
-                border_color: Color::GRAY.into(),
-                background_color: Color::BLACK.into(),
                 ..default()
             },
             TrackedInteraction::default(),
             Draggable::default(),
+            TokenStyle {
+                background: ColorToken::Surface.into(),
+                border: ColorToken::OutlineVariant.into(),
+                ..default()
+            },
         )
     }
 
@@ -653,11 +677,14 @@ impl FloatingPanel {
                     border: UiRect::left(Val::Px(2.)),
                     ..default()
                 },
-                border_color: Color::BLACK.into(),
-                background_color: Color::GRAY.into(),
                 focus_policy: bevy::ui::FocusPolicy::Block,
                 ..default()
             },
+            TokenStyle {
+                background: ColorToken::SurfaceRaised.into(),
+                border: ColorToken::Outline.into(),
+                ..default()
+            },
         )
     }
 }
@@ -845,19 +872,20 @@ impl<'w, 's> UiFloatingPanelExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
                             Name::new("Fold Button"),
                             Interaction::default(),
                             TrackedInteraction::default(),
-                            InteractiveBackground {
-                                highlight: Color::rgba(0., 1., 1., 1.).into(),
-                                ..default()
-                            },
+                            InteractiveBackground::default(),
                             AnimatedInteraction::<InteractiveBackground> {
                                 tween: FloatingPanel::base_tween(),
                                 ..default()
                             },
+                            TokenStyle {
+                                background: ColorToken::SurfaceRaised.into(),
+                                highlight: ColorToken::Accent.into(),
+                                ..default()
+                            },
                             FloatingPanelFoldButton { panel },
                         ))
                         .style()
                         .margin(UiRect::px(3., 0., 3., 3.))
-                        .background_color(Color::GRAY)
                         .render(config.foldable)
                         .id();
 
@@ -865,7 +893,6 @@ impl<'w, 's> UiFloatingPanelExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
                         .label(LabelConfig {
                             label: title_text.clone(),
                             margin: UiRect::px(5., 29., 5., 2.),
-                            color: Color::ANTIQUE_WHITE,
                             ..default()
                         })
                         .style()
@@ -881,19 +908,20 @@ impl<'w, 's> UiFloatingPanelExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
                                     Name::new("Close Button"),
                                     Interaction::default(),
                                     TrackedInteraction::default(),
-                                    InteractiveBackground {
-                                        highlight: Color::rgba(0., 1., 1., 1.).into(),
-                                        ..default()
-                                    },
+                                    InteractiveBackground::default(),
                                     AnimatedInteraction::<InteractiveBackground> {
                                         tween: FloatingPanel::base_tween(),
                                         ..default()
                                     },
+                                    TokenStyle {
+                                        background: ColorToken::Surface.into(),
+                                        highlight: ColorToken::Accent.into(),
+                                        ..default()
+                                    },
                                     FloatingPanelCloseButton { panel },
                                 ))
                                 .style()
                                 .margin(UiRect::px(3., 2., 2., 3.))
-                                .background_color(Color::rgb(0.1, 0.1, 0.1))
                                 .render(config.closable)
                                 .id();
                         },
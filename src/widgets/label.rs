@@ -4,14 +4,20 @@ use bevy::{
     ui::FocusPolicy,
 };
 
-use crate::ui_builder::*;
+use crate::{
+    theme::tokens::{ColorToken, FontSizeToken, FontToken, ThemeTokens, TokenRect, TokenStyle},
+    ui_builder::*,
+};
 
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub struct LabelConfig {
     pub label: String,
-    pub color: Color,
+    /// Overrides the `OnSurface` color token
+    pub color: Option<Color>,
     pub margin: UiRect,
+    /// Margin from the spacing tokens, takes precedence over `margin`
+    pub spacing: Option<TokenRect>,
     pub wrap: FlexWrap,
     pub flex_grow: f32,
 }
@@ -20,8 +26,9 @@ impl Default for LabelConfig {
     fn default() -> Self {
         Self {
             label: "Label".into(),
-            color: Color::ANTIQUE_WHITE,
+            color: None,
             margin: Default::default(),
+            spacing: None,
             wrap: FlexWrap::NoWrap,
             flex_grow: 0.,
         }
@@ -36,16 +43,31 @@ impl LabelConfig {
         }
     }
 
-    fn text_style(&self) -> TextStyle {
-        TextStyle {
-            color: self.color,
-            font_size: 14.,
+    fn text_style(&self, tokens: &ThemeTokens) -> TextStyle {
+        let mut style = tokens.text_style(ColorToken::OnSurface, FontSizeToken::Medium);
+        if let Some(color) = self.color {
+            style.color = color;
+        }
+
+        style
+    }
+
+    fn token_style(&self) -> TokenStyle {
+        TokenStyle {
+            text: match self.color {
+                Some(_) => None,
+                None => ColorToken::OnSurface.into(),
+            },
+            font_size: FontSizeToken::Medium.into(),
+            font: FontToken::Regular.into(),
+            margin: self.spacing,
             ..default()
         }
     }
 
     fn frame(self) -> impl Bundle {
-        let mut section = Text::from_section(self.label.clone(), self.text_style());
+        // Text style is resolved from the tokens by the `TokenStyle`
+        let mut section = Text::from_section(self.label.clone(), TextStyle::default());
 
         if self.wrap == FlexWrap::NoWrap {
             section = section.with_no_wrap();
@@ -65,6 +87,7 @@ impl LabelConfig {
                 focus_policy: FocusPolicy::Pass,
                 ..default()
             },
+            self.token_style(),
             self,
         )
     }
@@ -94,7 +117,10 @@ impl EntityCommand for UpdateLabelText {
 
             return;
         };
-        let style = config.text_style();
+        let style = match world.get_resource::<ThemeTokens>() {
+            Some(tokens) => config.text_style(tokens),
+            None => config.text_style(&ThemeTokens::default()),
+        };
         let Some(mut text) = world.get_mut::<Text>(entity) else {
             warn!(
                 "Failed to set label text on entity {:?}: No Text component found!",
@@ -3,7 +3,9 @@ use sickle_math::ease::Ease;
 
 use crate::{
     animated_interaction::{AnimatedInteraction, AnimationConfig},
-    interactions::InteractiveBackground,
+    focus_interaction::{ConfineFocus, Focusable},
+    interactions::{InteractiveBackground, PseudoStates},
+    theme::tokens::{ColorToken, ThemeTokens, TokenStyle},
     ui_builder::*,
     ui_style::{SetBorderColorExt, SetEntityVisiblityExt, UiStyleExt},
     FluxInteraction, FluxInteractionUpdate, TrackedInteraction,
@@ -136,10 +138,15 @@ fn handle_item_interaction(
 }
 
 fn update_menu_container_visibility(
-    q_menus: Query<(Entity, &Menu), Changed<Menu>>,
+    q_menus: Query<(Entity, Ref<Menu>)>,
+    tokens: Res<ThemeTokens>,
     mut commands: Commands,
 ) {
     for (entity, menu) in &q_menus {
+        if !menu.is_changed() && !tokens.is_changed() {
+            continue;
+        }
+
         commands
             .style(menu.container)
             .visibility(match menu.is_open {
@@ -148,7 +155,7 @@ fn update_menu_container_visibility(
             });
 
         commands.style(entity).border_color(match menu.is_open {
-            true => Color::ANTIQUE_WHITE,
+            true => tokens.color(ColorToken::OnSurface),
             false => Color::NONE,
         });
     }
@@ -200,8 +207,11 @@ impl Menu {
                 ..default()
             },
             TrackedInteraction::default(),
-            InteractiveBackground {
-                highlight: Color::DARK_GRAY.into(),
+            PseudoStates::default(),
+            Focusable::default(),
+            InteractiveBackground::default(),
+            TokenStyle {
+                highlight: ColorToken::Active.into(),
                 ..default()
             },
             AnimatedInteraction::<InteractiveBackground> {
@@ -227,13 +237,17 @@ impl Menu {
                     ..default()
                 },
                 z_index: ZIndex::Global(MENU_CONTAINER_Z_INDEX),
-                background_color: Color::rgb(0.1, 0.1, 0.1).into(),
-                border_color: Color::ANTIQUE_WHITE.into(),
                 focus_policy: bevy::ui::FocusPolicy::Block,
                 visibility: Visibility::Hidden,
                 ..default()
             },
             Interaction::default(),
+            ConfineFocus,
+            TokenStyle {
+                background: ColorToken::Surface.into(),
+                border: ColorToken::OnSurface.into(),
+                ..default()
+            },
         )
     }
 }
@@ -259,7 +273,6 @@ impl<'w, 's> UiMenuExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
             container = menu_button.container(Menu::container(), spawn_items).id();
             menu_button.label(LabelConfig {
                 label: config.name.clone(),
-                color: Color::ANTIQUE_WHITE,
                 ..default()
             });
         });
@@ -282,16 +295,21 @@ pub struct MenuSeparator;
 
 impl MenuSeparator {
     fn separator() -> impl Bundle {
-        NodeBundle {
-            style: Style {
-                height: Val::Px(12.),
-                width: Val::Px(1.),
-                margin: UiRect::horizontal(Val::Px(5.)),
+        (
+            NodeBundle {
+                style: Style {
+                    height: Val::Px(12.),
+                    width: Val::Px(1.),
+                    margin: UiRect::horizontal(Val::Px(5.)),
+                    ..default()
+                },
                 ..default()
             },
-            background_color: Color::ANTIQUE_WHITE.into(),
-            ..default()
-        }
+            TokenStyle {
+                background: ColorToken::OnSurface.into(),
+                ..default()
+            },
+        )
     }
 }
 
@@ -311,16 +329,21 @@ pub struct MenuItemSeparator;
 
 impl MenuItemSeparator {
     fn separator() -> impl Bundle {
-        NodeBundle {
-            style: Style {
-                min_width: Val::Px(100.),
-                height: Val::Px(1.),
-                margin: UiRect::px(5., 5., 5., 5.),
+        (
+            NodeBundle {
+                style: Style {
+                    min_width: Val::Px(100.),
+                    height: Val::Px(1.),
+                    margin: UiRect::px(5., 5., 5., 5.),
+                    ..default()
+                },
                 ..default()
             },
-            background_color: Color::ANTIQUE_WHITE.into(),
-            ..default()
-        }
+            TokenStyle {
+                background: ColorToken::OnSurface.into(),
+                ..default()
+            },
+        )
     }
 }
 
@@ -3,9 +3,18 @@ use sickle_math::ease::Ease;
 
 use crate::{
     animated_interaction::{AnimatedInteraction, AnimationConfig},
+    focus_interaction::Focusable,
     input_extension::{ShortcutTextExt, SymmetricKeysExt},
-    interactions::InteractiveBackground,
+    interactions::{InteractiveBackground, PseudoStates},
+    theme::{
+        tokens::{ColorToken, SpacingToken, TokenRect, TokenStyle},
+        ComponentThemePlugin,
+    },
     ui_builder::*,
+    ui_callbacks::{
+        report_ui_callbacks, UiCallbackEvent, UiCallbackTrigger, UiCallbackTriggered,
+        UiCallbackUpdate,
+    },
     ui_style::{SetBackgroundColorExt, SetImageExt, UiStyleExt},
     FluxInteraction, FluxInteractionUpdate, TrackedInteraction,
 };
@@ -21,50 +30,76 @@ pub struct MenuItemPlugin;
 
 impl Plugin for MenuItemPlugin {
     fn build(&self, app: &mut App) {
-        app.configure_sets(
-            Update,
-            MenuItemUpdate
-                .after(FluxInteractionUpdate)
-                .before(MenuUpdate)
-                .before(SubmenuUpdate)
-                .before(ContextMenuUpdate),
-        )
-        .add_systems(
-            Update,
-            (
-                update_menu_item_on_change,
-                update_menu_item_on_pressed,
-                update_menu_item_on_key_press,
-                update_menu_item_on_config_change,
+        app.add_plugins(ComponentThemePlugin::<MenuItem>::default())
+            .add_event::<MenuItemActivated>()
+            .add_event::<UiCallbackTriggered>()
+            .add_systems(
+                Update,
+                report_ui_callbacks::<MenuItemActivated>
+                    .after(MenuItemUpdate)
+                    .before(UiCallbackUpdate),
+            )
+            .configure_sets(
+                Update,
+                MenuItemUpdate
+                    .after(FluxInteractionUpdate)
+                    .before(MenuUpdate)
+                    .before(SubmenuUpdate)
+                    .before(ContextMenuUpdate),
             )
-                .chain()
-                .in_set(MenuItemUpdate),
-        );
+            .add_systems(
+                Update,
+                (
+                    update_menu_item_on_change,
+                    update_menu_item_on_pressed,
+                    update_menu_item_on_key_press,
+                    update_menu_item_on_config_change,
+                )
+                    .chain()
+                    .in_set(MenuItemUpdate),
+            );
     }
 }
 
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct MenuItemUpdate;
 
+/// Sent when a menu item is released or its shortcut is pressed
+#[derive(Event, Clone, Copy, Debug)]
+pub struct MenuItemActivated {
+    pub entity: Entity,
+}
+
+impl UiCallbackEvent for MenuItemActivated {
+    const TRIGGER: UiCallbackTrigger = UiCallbackTrigger::Press;
+
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
 fn update_menu_item_on_pressed(
-    mut q_menu_items: Query<(&mut MenuItem, &FluxInteraction), Changed<FluxInteraction>>,
+    mut q_menu_items: Query<(Entity, &mut MenuItem, &FluxInteraction), Changed<FluxInteraction>>,
+    mut e_activated: EventWriter<MenuItemActivated>,
 ) {
-    for (mut item, interaction) in &mut q_menu_items {
+    for (entity, mut item, interaction) in &mut q_menu_items {
         if *interaction == FluxInteraction::Released {
             item.interacted = true;
+            e_activated.send(MenuItemActivated { entity });
         }
     }
 }
 
 fn update_menu_item_on_key_press(
-    mut q_menu_items: Query<(&mut MenuItem, &MenuItemConfig)>,
+    mut q_menu_items: Query<(Entity, &mut MenuItem, &MenuItemConfig)>,
     r_keys: Res<ButtonInput<KeyCode>>,
+    mut e_activated: EventWriter<MenuItemActivated>,
 ) {
     if !r_keys.is_changed() {
         return;
     }
 
-    for (mut item, config) in &mut q_menu_items {
+    for (entity, mut item, config) in &mut q_menu_items {
         if let Some(shortcut) = &config.shortcut {
             if shortcut.len() == 0 {
                 continue;
@@ -80,9 +115,11 @@ fn update_menu_item_on_key_press(
                         .all(|keycode| r_keys.symmetry_pressed(keycode))
                     {
                         item.interacted = true;
+                        e_activated.send(MenuItemActivated { entity });
                     }
                 } else {
                     item.interacted = true;
+                    e_activated.send(MenuItemActivated { entity });
                 }
             }
         }
@@ -107,22 +144,25 @@ fn update_menu_item_on_config_change(
             Some(vec) => vec.shortcut_text().into(),
             None => None,
         };
-        let leading = config.leading_icon.clone();
-        let trailing = config.trailing_icon.clone();
+        let icons = [
+            (menu_item.leading, config.leading_icon.clone(), false),
+            (menu_item.trailing, config.trailing_icon.clone(), true),
+        ];
 
-        if let Some(leading) = leading {
-            commands
-                .entity(menu_item.leading)
-                .try_insert(UiImage::default());
-            commands
-                .style(menu_item.leading)
-                .image(leading)
-                .background_color(Color::WHITE);
-        } else {
-            commands.entity(menu_item.leading).remove::<UiImage>();
-            commands
-                .style(menu_item.leading)
-                .background_color(Color::NONE);
+        for (node, icon, trailing) in icons {
+            if let Some(icon) = icon {
+                commands.entity(node).try_insert((
+                    UiImage::default(),
+                    MenuItem::icon_token_style(trailing, true),
+                ));
+                commands.style(node).image(icon);
+            } else {
+                commands.entity(node).remove::<UiImage>();
+                commands
+                    .entity(node)
+                    .try_insert(MenuItem::icon_token_style(trailing, false));
+                commands.style(node).background_color(Color::NONE);
+            }
         }
 
         commands.entity(menu_item.label).set_label_text(name);
@@ -134,21 +174,6 @@ fn update_menu_item_on_config_change(
         } else {
             commands.entity(menu_item.shortcut).set_label_text("");
         }
-
-        if let Some(trailing) = trailing {
-            commands
-                .entity(menu_item.trailing)
-                .try_insert(UiImage::default());
-            commands
-                .style(menu_item.trailing)
-                .image(trailing)
-                .background_color(Color::WHITE);
-        } else {
-            commands.entity(menu_item.trailing).remove::<UiImage>();
-            commands
-                .style(menu_item.trailing)
-                .background_color(Color::NONE);
-        }
     }
 }
 
@@ -212,8 +237,11 @@ impl MenuItem {
                 ..default()
             },
             TrackedInteraction::default(),
-            InteractiveBackground {
-                highlight: Color::rgba(0.9, 0.8, 0.7, 0.5).into(),
+            PseudoStates::default(),
+            Focusable::default(),
+            InteractiveBackground::default(),
+            TokenStyle {
+                highlight: ColorToken::Highlight.into(),
                 ..default()
             },
             AnimatedInteraction::<InteractiveBackground> {
@@ -239,6 +267,21 @@ impl MenuItem {
         )
     }
 
+    /// Icons are tinted with the text color while they have an image
+    fn icon_token_style(trailing: bool, tinted: bool) -> TokenStyle {
+        TokenStyle {
+            background: match tinted {
+                true => ColorToken::OnSurface.into(),
+                false => None,
+            },
+            margin: match trailing {
+                true => TokenRect::left(SpacingToken::Small).into(),
+                false => None,
+            },
+            ..default()
+        }
+    }
+
     fn leading_icon() -> impl Bundle {
         (
             Name::new("Leading Icon"),
@@ -260,11 +303,11 @@ impl MenuItem {
                 style: Style {
                     width: Val::Px(12.),
                     aspect_ratio: (1.).into(),
-                    margin: UiRect::left(Val::Px(5.)),
                     ..default()
                 },
                 ..default()
             },
+            MenuItem::icon_token_style(true, false),
         )
     }
 }
@@ -286,8 +329,7 @@ impl<'w, 's> UiMenuItemExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
             label = menu_item
                 .label(LabelConfig {
                     label: "".into(),
-                    margin: UiRect::horizontal(Val::Px(5.)),
-                    color: Color::ANTIQUE_WHITE,
+                    spacing: TokenRect::horizontal(SpacingToken::Small).into(),
                     ..default()
                 })
                 .id();
@@ -295,8 +337,7 @@ impl<'w, 's> UiMenuItemExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
                 shortcut = shortcut_container
                     .label(LabelConfig {
                         label: "".into(),
-                        margin: UiRect::horizontal(Val::Px(5.)),
-                        color: Color::ANTIQUE_WHITE,
+                        spacing: TokenRect::horizontal(SpacingToken::Small).into(),
                         ..default()
                     })
                     .id();
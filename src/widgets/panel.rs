@@ -4,6 +4,20 @@ use crate::ui_builder::UiBuilder;
 
 use super::prelude::UiContainerExt;
 
+pub struct PanelPlugin;
+
+impl Plugin for PanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PanelClosed>();
+    }
+}
+
+/// Sent when a panel is closed from its floating panel or tab
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PanelClosed {
+    pub entity: Entity,
+}
+
 #[derive(Component, Clone, Debug, Reflect)]
 #[reflect(Component)]
 pub struct Panel {
@@ -5,8 +5,17 @@ use sickle_math::ease::Ease;
 
 use crate::{
     animated_interaction::{AnimatedInteraction, AnimationConfig},
-    interactions::InteractiveBackground,
+    focus_interaction::{ConfineFocus, Focusable},
+    interactions::{set_pseudo_state, InteractiveBackground, PseudoState, PseudoStates},
+    theme::{
+        tokens::{ColorToken, SpacingToken, TokenRect, TokenStyle},
+        ComponentThemePlugin,
+    },
     ui_builder::UiBuilder,
+    ui_callbacks::{
+        report_ui_callbacks, UiCallbackEvent, UiCallbackTrigger, UiCallbackTriggered,
+        UiCallbackUpdate,
+    },
     FluxInteraction, FluxInteractionUpdate, TrackedInteraction,
 };
 
@@ -21,34 +30,58 @@ pub struct RadioGroupPlugin;
 
 impl Plugin for RadioGroupPlugin {
     fn build(&self, app: &mut App) {
-        app.configure_sets(
-            Update,
-            RadioGroupUpdate
-                .after(FluxInteractionUpdate)
-                .before(MenuUpdate)
-                .before(SubmenuUpdate)
-                .before(ContextMenuUpdate),
-        )
-        .add_systems(
-            Update,
-            (
-                toggle_radio_button,
-                update_radio_group_buttons,
-                update_radio_button,
+        app.add_plugins(ComponentThemePlugin::<RadioButton>::default())
+            .add_event::<RadioSelected>()
+            .add_event::<UiCallbackTriggered>()
+            .add_systems(
+                Update,
+                report_ui_callbacks::<RadioSelected>
+                    .after(RadioGroupUpdate)
+                    .before(UiCallbackUpdate),
             )
-                .chain()
-                .in_set(RadioGroupUpdate),
-        );
+            .configure_sets(
+                Update,
+                RadioGroupUpdate
+                    .after(FluxInteractionUpdate)
+                    .before(MenuUpdate)
+                    .before(SubmenuUpdate)
+                    .before(ContextMenuUpdate),
+            )
+            .add_systems(
+                Update,
+                (
+                    toggle_radio_button,
+                    update_radio_group_buttons,
+                    update_radio_button,
+                )
+                    .chain()
+                    .in_set(RadioGroupUpdate),
+            );
     }
 }
 
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct RadioGroupUpdate;
 
+#[derive(Event, Clone, Copy, Debug)]
+pub struct RadioSelected {
+    pub entity: Entity,
+    pub selected: Option<usize>,
+}
+
+impl UiCallbackEvent for RadioSelected {
+    const TRIGGER: UiCallbackTrigger = UiCallbackTrigger::Change;
+
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
 fn toggle_radio_button(
     mut q_radio_buttons: Query<(&mut RadioButton, &FluxInteraction), Changed<FluxInteraction>>,
     keys: Res<ButtonInput<KeyCode>>,
     mut q_group: Query<&mut RadioGroup>,
+    mut e_selected: EventWriter<RadioSelected>,
 ) {
     for (mut radio_button, interaction) in &mut q_radio_buttons {
         if *interaction == FluxInteraction::Pressed {
@@ -79,6 +112,10 @@ fn toggle_radio_button(
                 } else {
                     None
                 };
+                e_selected.send(RadioSelected {
+                    entity: group,
+                    selected: radio_group.selected,
+                });
             }
         }
     }
@@ -102,10 +139,14 @@ fn update_radio_group_buttons(
 }
 
 fn update_radio_button(
-    q_checkboxes: Query<&RadioButton, Changed<RadioButton>>,
+    mut q_checkboxes: Query<(&RadioButton, Option<&mut PseudoStates>), Changed<RadioButton>>,
     mut q_visibility: Query<&mut Visibility>,
 ) {
-    for checkbox in &q_checkboxes {
+    for (checkbox, pseudo_states) in &mut q_checkboxes {
+        if let Some(mut pseudo_states) = pseudo_states {
+            set_pseudo_state(&mut pseudo_states, PseudoState::Selected, checkbox.checked);
+        }
+
         if let Ok(mut visiblity) = q_visibility.get_mut(checkbox.check_node) {
             *visiblity = match checkbox.checked {
                 true => Visibility::Inherited,
@@ -173,8 +214,11 @@ impl RadioButton {
                 ..default()
             },
             TrackedInteraction::default(),
-            InteractiveBackground {
-                highlight: Color::rgba(0., 1., 1., 0.3).into(),
+            Focusable::default(),
+            PseudoStates::default(),
+            InteractiveBackground::default(),
+            TokenStyle {
+                highlight: ColorToken::Highlight.into(),
                 ..default()
             },
             AnimatedInteraction::<InteractiveBackground> {
@@ -195,11 +239,14 @@ impl RadioButton {
                     border: UiRect::all(Val::Px(1.)),
                     ..default()
                 },
-                border_color: Color::DARK_GRAY.into(),
-                background_color: Color::ANTIQUE_WHITE.into(),
                 focus_policy: FocusPolicy::Pass,
                 ..default()
             },
+            TokenStyle {
+                background: ColorToken::Input.into(),
+                border: ColorToken::Outline.into(),
+                ..default()
+            },
         )
     }
 
@@ -213,10 +260,13 @@ impl RadioButton {
                     margin: UiRect::all(Val::Px(2.)),
                     ..default()
                 },
-                background_color: Color::DARK_GRAY.into(),
                 focus_policy: FocusPolicy::Pass,
                 ..default()
             },
+            TokenStyle {
+                background: ColorToken::OnInput.into(),
+                ..default()
+            },
         )
     }
 }
@@ -242,6 +292,7 @@ impl<'w, 's> UiRadioGroupExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
                 Name::new("Radio Group"),
                 NodeBundle::default(),
                 RadioGroup::default(),
+                ConfineFocus,
             ),
             |radio_group| {
                 for i in 0..option_count {
@@ -261,7 +312,7 @@ impl<'w, 's> UiRadioGroupExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
                             );
                             button.label(LabelConfig {
                                 label: label_string,
-                                margin: UiRect::right(Val::Px(10.)),
+                                spacing: TokenRect::right(SpacingToken::Medium).into(),
                                 ..default()
                             });
                         })
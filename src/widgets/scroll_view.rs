@@ -6,6 +6,10 @@ use crate::{
     drag_interaction::{DragState, Draggable, DraggableUpdate},
     interactions::InteractiveBackground,
     scroll_interaction::{ScrollAxis, Scrollable, ScrollableUpdate},
+    theme::{
+        tokens::{ColorToken, TokenStyle},
+        ComponentThemePlugin,
+    },
     ui_builder::UiBuilder,
     ui_style::{SetNodePaddingExt, UiStyleExt},
     TrackedInteraction,
@@ -17,17 +21,18 @@ pub struct ScrollViewPlugin;
 
 impl Plugin for ScrollViewPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                update_scroll_view_on_content_change,
-                update_scroll_view_on_scroll.after(ScrollableUpdate),
-                update_scroll_view_on_drag.after(DraggableUpdate),
-                update_scroll_view_offset,
-                update_scroll_view_layout,
-            )
-                .chain(),
-        );
+        app.add_plugins(ComponentThemePlugin::<ScrollBarHandle>::default())
+            .add_systems(
+                Update,
+                (
+                    update_scroll_view_on_content_change,
+                    update_scroll_view_on_scroll.after(ScrollableUpdate),
+                    update_scroll_view_on_drag.after(DraggableUpdate),
+                    update_scroll_view_offset,
+                    update_scroll_view_layout,
+                )
+                    .chain(),
+            );
     }
 }
 
@@ -405,6 +410,21 @@ impl Default for ScrollView {
     }
 }
 
+impl ScrollView {
+    pub fn content_container_id(&self) -> Entity {
+        self.content_container
+    }
+
+    pub fn scroll_offset(&self) -> Vec2 {
+        self.scroll_offset
+    }
+
+    /// Scrolls the content, the offset is clamped to the overflow on the next update
+    pub fn set_scroll_offset(&mut self, offset: Vec2) {
+        self.scroll_offset = offset;
+    }
+}
+
 impl ScrollView {
     fn base_tween() -> AnimationConfig {
         AnimationConfig {
@@ -539,7 +559,10 @@ impl ScrollView {
                     justify_content: JustifyContent::Start,
                     ..default()
                 },
-                background_color: Color::GRAY.into(),
+                ..default()
+            },
+            TokenStyle {
+                background: ColorToken::SurfaceRaised.into(),
                 ..default()
             },
         )
@@ -560,12 +583,13 @@ impl ScrollView {
                     },
                     ..default()
                 },
-                background_color: Color::rgba(0., 1., 1., 0.4).into(),
                 ..default()
             },
             TrackedInteraction::default(),
-            InteractiveBackground {
-                highlight: Color::rgba(0., 1., 1., 0.8).into(),
+            InteractiveBackground::default(),
+            TokenStyle {
+                background: ColorToken::Highlight.into(),
+                highlight: ColorToken::Accent.into(),
                 ..default()
             },
             AnimatedInteraction::<InteractiveBackground> {
@@ -3,6 +3,7 @@ use bevy::{prelude::*, ui::UiSystem};
 use crate::{
     drag_interaction::{DragState, Draggable},
     resize_interaction::{ResizeDirection, ResizeHandle},
+    theme::tokens::{ColorToken, TokenStyle},
     ui_builder::*,
     ui_commands::LogHierarchyExt,
     ui_style::{SetEntityVisiblityExt, SetNodeLeftExt, SetNodeTopExt, UiStyleExt},
@@ -578,7 +579,10 @@ impl SizedZone {
                     height: Val::Percent(100.),
                     ..default()
                 },
-                border_color: Color::rgb(0.1, 0.1, 0.1).into(),
+                ..default()
+            },
+            TokenStyle {
+                border: ColorToken::Surface.into(),
                 ..default()
             },
         )
@@ -1,43 +1,137 @@
-use bevy::{input::mouse::MouseScrollUnit, prelude::*};
-use sickle_math::ease::Ease;
+use bevy::{
+    ecs::system::SystemParam, input::mouse::MouseScrollUnit, prelude::*, ui::RelativeCursorPosition,
+};
+use sickle_math::ease::{CustomEase, Ease, ValueEasing};
 
 use crate::{
     animated_interaction::{AnimatedInteraction, AnimationConfig},
     drag_interaction::{DragState, Draggable, DraggableUpdate},
+    focus_interaction::{
+        CaptureNavigationAxis, Focusable, FocusedEntity, NavigationDirection, NavigationInput,
+    },
     interactions::InteractiveBackground,
     scroll_interaction::{ScrollAxis, Scrollable, ScrollableUpdate},
+    theme::{
+        tokens::{ColorToken, FontSizeToken, SpacingToken, ThemeTokens, TokenRect, TokenStyle},
+        ComponentThemePlugin, LockedStyleAttributes, StylableAttribute,
+    },
     ui_builder::UiBuilder,
-    TrackedInteraction,
+    ui_callbacks::{
+        report_ui_callbacks, UiCallbackEvent, UiCallbackTrigger, UiCallbackTriggered,
+        UiCallbackUpdate,
+    },
+    ui_style::{SetNodeFlexGrowExt, SetNodeMarginExt, SetNodeWidthExt},
+    FluxInteraction, PressAndHoldRepeat, TrackedInteraction,
+};
+
+use super::{
+    prelude::{
+        LabelConfig, TextInput, TextInputConfig, TextInputSubmitted, UiContainerExt, UiLabelExt,
+        UiTextInputExt,
+    },
+    text_input::TextInputUpdate,
 };
 
-use super::prelude::{LabelConfig, UiContainerExt, UiLabelExt};
+/// Ratio change of a keyboard step on sliders without a step size
+const KEYBOARD_STEP_RATIO: f32 = 0.01;
+/// Steps moved by PageUp / PageDown and by paging clicks on the bar
+const PAGE_STEPS: f32 = 10.;
+/// Bisections finding the ratio of a value on eased scales
+const INVERSE_EASE_ITERATIONS: usize = 24;
 
 pub struct SliderPlugin;
 
 impl Plugin for SliderPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                update_slider_on_scroll.after(ScrollableUpdate),
-                update_slider_on_drag.after(DraggableUpdate),
-                update_slider_handle,
-                update_slider_readout,
+        app.add_plugins(ComponentThemePlugin::<SliderDragHandle>::default())
+            .add_event::<SliderChanged>()
+            .add_event::<SliderRangeChanged>()
+            .add_event::<UiCallbackTriggered>()
+            .add_systems(
+                Update,
+                (
+                    report_ui_callbacks::<SliderChanged>,
+                    report_ui_callbacks::<SliderRangeChanged>,
+                )
+                    .after(update_slider_readout)
+                    .before(UiCallbackUpdate),
             )
-                .chain(),
-        );
+            .add_systems(
+                Update,
+                (
+                    update_slider_on_scroll.after(ScrollableUpdate),
+                    update_slider_on_drag.after(DraggableUpdate),
+                    update_slider_on_bar_press,
+                    update_slider_on_key_press,
+                    update_slider_on_readout_submit.after(TextInputUpdate),
+                    update_slider_handle,
+                    update_slider_readout,
+                )
+                    .chain(),
+            );
+    }
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SliderChanged {
+    pub entity: Entity,
+    pub value: f32,
+}
+
+impl UiCallbackEvent for SliderChanged {
+    const TRIGGER: UiCallbackTrigger = UiCallbackTrigger::Change;
+
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+/// Sent instead of [`SliderChanged`] by range sliders
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SliderRangeChanged {
+    pub entity: Entity,
+    pub low: f32,
+    pub high: f32,
+}
+
+impl UiCallbackEvent for SliderRangeChanged {
+    const TRIGGER: UiCallbackTrigger = UiCallbackTrigger::Change;
+
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+#[derive(SystemParam)]
+struct SliderEvents<'w> {
+    changed: EventWriter<'w, SliderChanged>,
+    range_changed: EventWriter<'w, SliderRangeChanged>,
+}
+
+impl<'w> SliderEvents<'w> {
+    fn send(&mut self, entity: Entity, slider: &Slider) {
+        match slider.range() {
+            Some((low, high)) => {
+                self.range_changed
+                    .send(SliderRangeChanged { entity, low, high });
+            }
+            None => {
+                self.changed.send(SliderChanged {
+                    entity,
+                    value: slider.value(),
+                });
+            }
+        }
     }
 }
 
-// TODO: Remove hardcoded theme
-// TODO: Add input for value (w/ read/write flags)
-// TODO: Support click-on-bar value setting
 fn update_slider_on_scroll(
     q_scrollables: Query<
         (AnyOf<(&SliderBar, &SliderDragHandle)>, &Scrollable),
         Changed<Scrollable>,
     >,
     mut q_slider: Query<&mut Slider>,
+    mut events: SliderEvents,
 ) {
     for ((slider_bar, handle), scrollable) in &q_scrollables {
         let Some((axis, diff, unit)) = scrollable.last_change() else {
@@ -47,10 +141,10 @@ fn update_slider_on_scroll(
             continue;
         }
 
-        let slider_id = if let Some(slider_bar) = slider_bar {
-            slider_bar.slider
-        } else if let Some(handle) = handle {
-            handle.slider
+        let (slider_id, thumb) = if let Some(handle) = handle {
+            (handle.slider, handle.thumb)
+        } else if let Some(slider_bar) = slider_bar {
+            (slider_bar.slider, SliderThumb::Low)
         } else {
             continue;
         };
@@ -58,6 +152,10 @@ fn update_slider_on_scroll(
         let Ok(mut slider) = q_slider.get_mut(slider_id) else {
             continue;
         };
+        // Scrolling the bar of a range slider could move either end
+        if handle.is_none() && slider.is_range() {
+            continue;
+        }
 
         let offset = match unit {
             MouseScrollUnit::Line => -diff * 5.,
@@ -65,7 +163,19 @@ fn update_slider_on_scroll(
         };
 
         let fraction = offset / 100.;
-        slider.ratio = (slider.ratio + fraction).clamp(0., 1.);
+        let current = slider.thumb_ratio(thumb);
+        let mut ratio = slider.snap_ratio(current + fraction);
+        // Scrolling less than half a step still moves the slider
+        if ratio == current && slider.config.step > 0. {
+            ratio = slider.stepped_ratio(thumb, fraction.signum());
+        }
+        if slider
+            .bypass_change_detection()
+            .set_thumb_ratio(thumb, ratio)
+        {
+            slider.set_changed();
+            events.send(slider_id, &slider);
+        }
     }
 }
 
@@ -73,6 +183,7 @@ fn update_slider_on_drag(
     q_draggable: Query<(&Draggable, &SliderDragHandle, &Node), Changed<Draggable>>,
     q_node: Query<&Node>,
     mut q_slider: Query<&mut Slider>,
+    mut events: SliderEvents,
 ) {
     for (draggable, handle, node) in &q_draggable {
         let Ok(mut slider) = q_slider.get_mut(handle.slider) else {
@@ -85,13 +196,21 @@ fn update_slider_on_drag(
 
         if draggable.state == DragState::DragCanceled {
             if let Some(base_ratio) = slider.base_ratio {
-                slider.ratio = base_ratio;
+                let thumb = slider.drag_thumb.unwrap_or(handle.thumb);
+                slider.set_thumb_ratio(thumb, base_ratio);
+                events.send(handle.slider, &slider);
                 continue;
             }
         }
 
         if draggable.state == DragState::DragStart {
-            slider.base_ratio = slider.ratio.into();
+            let ratio = slider.thumb_ratio(handle.thumb);
+            slider.base_ratio = ratio.into();
+            slider.drag_ratio = ratio;
+            slider.drag_thumb = match slider.high_ratio == Some(slider.ratio) {
+                true => None,
+                false => handle.thumb.into(),
+            };
         }
 
         let Ok(slider_bar) = q_node.get(slider.slider_bar) else {
@@ -119,40 +238,266 @@ fn update_slider_on_drag(
             }
         };
 
-        slider.ratio = (slider.ratio + fraction).clamp(0., 1.);
+        let drag_ratio = (slider.drag_ratio + fraction).clamp(0., 1.);
+        // Stacked thumbs split toward the drag, the same as presses on the bar
+        let thumb = match slider.drag_thumb {
+            Some(thumb) => thumb,
+            None => {
+                // Wait for the drag to point into the bar, a thumb pushed out of it couldn't
+                // come back during this drag
+                let target = slider.drag_ratio + fraction;
+                if !(0. ..=1.).contains(&target) {
+                    continue;
+                }
+
+                let thumb = slider.closest_thumb(target);
+                slider.drag_thumb = thumb.into();
+                thumb
+            }
+        };
+        let ratio = slider.snap_ratio(drag_ratio);
+        slider.drag_ratio = drag_ratio;
+        if slider
+            .bypass_change_detection()
+            .set_thumb_ratio(thumb, ratio)
+        {
+            slider.set_changed();
+            events.send(handle.slider, &slider);
+        }
     }
 }
 
-fn update_slider_handle(
-    q_slider: Query<&Slider, Or<(Changed<Slider>, Changed<Node>)>>,
+fn update_slider_on_bar_press(
+    q_bars: Query<(
+        Entity,
+        &SliderBar,
+        Ref<FluxInteraction>,
+        &RelativeCursorPosition,
+        &Node,
+    )>,
     q_node: Query<&Node>,
-    mut q_hadle_style: Query<(&Node, &mut Style), With<SliderDragHandle>>,
+    mut e_repeat: EventReader<PressAndHoldRepeat>,
+    mut q_slider: Query<&mut Slider>,
+    mut focused: ResMut<FocusedEntity>,
+    mut events: SliderEvents,
 ) {
-    for slider in &q_slider {
-        let Ok(slider_bar) = q_node.get(slider.slider_bar) else {
+    let repeated: Vec<Entity> = e_repeat.read().map(|event| event.entity).collect();
+
+    for (entity, slider_bar, flux_interaction, cursor_position, node) in &q_bars {
+        let pressed =
+            flux_interaction.is_changed() && *flux_interaction == FluxInteraction::Pressed;
+        if !pressed && !repeated.contains(&entity) {
+            continue;
+        }
+
+        let Ok(mut slider) = q_slider.get_mut(slider_bar.slider) else {
+            continue;
+        };
+        if !pressed && slider.config.bar_click == SliderBarClick::Jump {
+            continue;
+        }
+        let Some(position) = cursor_position.normalized else {
             continue;
         };
-        let Ok((node, mut style)) = q_hadle_style.get_mut(slider.drag_handle) else {
+        let Ok(handle) = q_node.get(slider.drag_handle) else {
             continue;
         };
 
-        let axis = &slider.config.axis;
-        match axis {
+        // Ratio that centers the handle on the cursor
+        let position = position * node.size();
+        let target = match slider.config.axis {
             SliderAxis::Horizontal => {
-                let width = slider_bar.size().x - node.size().x;
-                let handle_position = width * slider.ratio;
-                if style.left != Val::Px(handle_position) {
-                    style.left = Val::Px(handle_position);
+                let width = node.size().x - handle.size().x;
+                if width <= 0. {
+                    continue;
                 }
+                (position.x - handle.size().x / 2.) / width
             }
             SliderAxis::Vertical => {
-                let height = slider_bar.size().y - node.size().y;
-                let handle_position = height * (1. - slider.ratio);
-                if style.top != Val::Px(handle_position) {
-                    style.top = Val::Px(handle_position);
+                let height = node.size().y - handle.size().y;
+                if height <= 0. {
+                    continue;
                 }
+                1. - (position.y - handle.size().y / 2.) / height
             }
         }
+        .clamp(0., 1.);
+
+        let thumb = slider.closest_thumb(target);
+        let current = slider.thumb_ratio(thumb);
+        let ratio = match slider.config.bar_click {
+            SliderBarClick::Jump => slider.snap_ratio(target),
+            SliderBarClick::Page => {
+                let direction = (target - current).signum();
+                let ratio = slider.stepped_ratio(thumb, direction * PAGE_STEPS);
+                match (ratio - target) * direction > 0. {
+                    true => slider.snap_ratio(target),
+                    false => ratio,
+                }
+            }
+        };
+
+        if pressed {
+            focused.0 = slider.thumb_handle(thumb).into();
+        }
+        if slider
+            .bypass_change_detection()
+            .set_thumb_ratio(thumb, ratio)
+        {
+            slider.set_changed();
+            events.send(slider_bar.slider, &slider);
+        }
+    }
+}
+
+fn update_slider_on_key_press(
+    navigation: NavigationInput,
+    keys: Res<ButtonInput<KeyCode>>,
+    focused: Res<FocusedEntity>,
+    q_handle: Query<&SliderDragHandle>,
+    mut q_slider: Query<&mut Slider>,
+    mut events: SliderEvents,
+) {
+    // The press that moved the focus onto the handle doesn't step it too
+    if focused.is_changed() {
+        return;
+    }
+
+    let Some(handle) = focused.0.and_then(|entity| q_handle.get(entity).ok()) else {
+        return;
+    };
+    let Ok(mut slider) = q_slider.get_mut(handle.slider) else {
+        return;
+    };
+
+    let thumb = handle.thumb;
+
+    // Directions across the axis move the focus instead
+    let steps = match (slider.config.axis, navigation.direction()) {
+        (SliderAxis::Horizontal, Some(NavigationDirection::Right))
+        | (SliderAxis::Vertical, Some(NavigationDirection::Up)) => Some(1.),
+        (SliderAxis::Horizontal, Some(NavigationDirection::Left))
+        | (SliderAxis::Vertical, Some(NavigationDirection::Down)) => Some(-1.),
+        _ => None,
+    };
+
+    let ratio = match steps {
+        Some(steps) => slider.stepped_ratio(thumb, steps),
+        None if keys.just_pressed(KeyCode::PageUp) => slider.stepped_ratio(thumb, PAGE_STEPS),
+        None if keys.just_pressed(KeyCode::PageDown) => slider.stepped_ratio(thumb, -PAGE_STEPS),
+        None if keys.just_pressed(KeyCode::Home) => 0.,
+        None if keys.just_pressed(KeyCode::End) => 1.,
+        None => return,
+    };
+
+    if slider
+        .bypass_change_detection()
+        .set_thumb_ratio(thumb, ratio)
+    {
+        slider.set_changed();
+        events.send(handle.slider, &slider);
+    }
+}
+
+/// Applies values typed into the readout, restores the readout once it loses focus
+fn update_slider_on_readout_submit(
+    mut e_submitted: EventReader<TextInputSubmitted>,
+    focused: Res<FocusedEntity>,
+    mut q_slider: Query<(Entity, &mut Slider)>,
+    mut q_text_input: Query<&mut TextInput>,
+    mut events: SliderEvents,
+) {
+    for event in e_submitted.read() {
+        let Some((entity, mut slider)) = q_slider
+            .iter_mut()
+            .find(|(_, slider)| slider.readout_target == Some(event.entity))
+        else {
+            continue;
+        };
+
+        match slider.config.format.parse(&event.value) {
+            Some(value) => {
+                let value = value.clamp(slider.config.min, slider.config.max);
+                let ratio = slider.snap_ratio(slider.value_to_ratio(value));
+                if slider
+                    .bypass_change_detection()
+                    .set_thumb_ratio(SliderThumb::Low, ratio)
+                {
+                    slider.set_changed();
+                    events.send(entity, &slider);
+                }
+            }
+            None => warn!(
+                "Failed to set value of slider {:?}: {:?} is not a number",
+                entity, event.value
+            ),
+        }
+
+        if let Ok(mut text_input) = q_text_input.get_mut(event.entity) {
+            text_input.set_value(slider.readout());
+        }
+    }
+
+    if !focused.is_changed() {
+        return;
+    }
+
+    for (_, slider) in &q_slider {
+        let Some(readout_target) = slider.readout_target else {
+            continue;
+        };
+        if focused.0 == Some(readout_target) {
+            continue;
+        }
+        let Ok(mut text_input) = q_text_input.get_mut(readout_target) else {
+            continue;
+        };
+
+        let content = slider.readout();
+        if text_input.value() != content {
+            text_input.set_value(content);
+        }
+    }
+}
+
+fn update_slider_handle(
+    q_slider: Query<&Slider, Or<(Changed<Slider>, Changed<Node>)>>,
+    q_node: Query<&Node>,
+    mut q_hadle_style: Query<(&Node, &mut Style), With<SliderDragHandle>>,
+) {
+    for slider in &q_slider {
+        let Ok(slider_bar) = q_node.get(slider.slider_bar) else {
+            continue;
+        };
+
+        // The high handle follows the low one in the layout
+        let mut flow_offset = Vec2::ZERO;
+        for &thumb in slider.thumbs() {
+            let Ok((node, mut style)) = q_hadle_style.get_mut(slider.thumb_handle(thumb)) else {
+                continue;
+            };
+
+            let ratio = slider.thumb_ratio(thumb);
+            let axis = &slider.config.axis;
+            match axis {
+                SliderAxis::Horizontal => {
+                    let width = slider_bar.size().x - node.size().x;
+                    let handle_position = width * ratio - flow_offset.x;
+                    if style.left != Val::Px(handle_position) {
+                        style.left = Val::Px(handle_position);
+                    }
+                }
+                SliderAxis::Vertical => {
+                    let height = slider_bar.size().y - node.size().y;
+                    let handle_position = height * (1. - ratio) - flow_offset.y;
+                    if style.top != Val::Px(handle_position) {
+                        style.top = Val::Px(handle_position);
+                    }
+                }
+            }
+
+            flow_offset += node.size();
+        }
     }
 }
 
@@ -160,36 +505,42 @@ fn update_slider_readout(
     q_slider: Query<&Slider, Changed<Slider>>,
     mut q_visibility: Query<&mut Visibility>,
     mut q_text: Query<&mut Text>,
+    mut q_text_input: Query<&mut TextInput>,
+    focused: Res<FocusedEntity>,
+    tokens: Res<ThemeTokens>,
 ) {
     for slider in &q_slider {
         let Some(readout_target) = slider.readout_target else {
             continue;
         };
-        let Ok(mut text) = q_text.get_mut(readout_target) else {
-            continue;
-        };
         let Ok(mut visibility) = q_visibility.get_mut(readout_target) else {
             continue;
         };
 
-        if slider.config.show_current {
-            if *visibility == Visibility::Hidden {
-                *visibility = Visibility::Inherited;
+        if !slider.config.show_current {
+            if *visibility == Visibility::Inherited {
+                *visibility = Visibility::Hidden;
             }
+            continue;
+        }
 
-            let content = format!("{:.1}", slider.value());
+        if *visibility == Visibility::Hidden {
+            *visibility = Visibility::Inherited;
+        }
+
+        let content = slider.readout();
+        if let Ok(mut text_input) = q_text_input.get_mut(readout_target) {
+            // Don't overwrite what is being typed
+            if focused.0 != Some(readout_target) && text_input.value() != content {
+                text_input.set_value(content);
+            }
+        } else if let Ok(mut text) = q_text.get_mut(readout_target) {
             let section = TextSection {
                 value: content,
-                style: TextStyle {
-                    color: Color::ANTIQUE_WHITE,
-                    font_size: 14.,
-                    ..default()
-                },
+                style: tokens.text_style(ColorToken::OnSurface, FontSizeToken::Medium),
             };
 
             text.sections = vec![section];
-        } else if !slider.config.show_current && *visibility == Visibility::Inherited {
-            *visibility = Visibility::Hidden;
         }
     }
 }
@@ -201,6 +552,114 @@ pub enum SliderAxis {
     Vertical,
 }
 
+/// Maps the position of the handle to the value
+#[derive(Copy, Clone, Debug, Default, PartialEq, Reflect)]
+pub enum SliderScale {
+    #[default]
+    Linear,
+    /// Equal distances multiply the value by the same factor, i.e. for frequencies.
+    /// Needs a positive range.
+    Logarithmic,
+    /// Eases the position before interpolating between min and max.
+    /// The curve should rise steadily, so values can be mapped back to positions.
+    /// Overshooting eases are clamped to the range.
+    Ease(Ease),
+}
+
+impl SliderScale {
+    /// A scale following `curve`, registered as a [`CustomEase`] under `name`
+    pub fn curve(name: impl Into<String>, curve: fn(f32) -> f32) -> Self {
+        Self::Ease(Ease::Custom(CustomEase::register(name, curve)))
+    }
+}
+
+/// Which value of the slider a handle drives, single value sliders only have the low one
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Reflect)]
+pub enum SliderThumb {
+    #[default]
+    Low,
+    High,
+}
+
+/// What pressing the bar outside the handle does
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Reflect)]
+pub enum SliderBarClick {
+    /// Moves the handle to the cursor
+    #[default]
+    Jump,
+    /// Moves the handle a page toward the cursor, repeating while held
+    Page,
+}
+
+/// How the current value is shown in (and parsed from) the readout
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct SliderFormat {
+    pub precision: usize,
+    /// Unit appended to the value, i.e. `" Hz"` or `"°"`
+    pub suffix: String,
+    /// Shows the value times 100 with a `%` suffix, for 0 - 1 ranges
+    pub percent: bool,
+}
+
+impl Default for SliderFormat {
+    fn default() -> Self {
+        Self {
+            precision: 1,
+            suffix: String::new(),
+            percent: false,
+        }
+    }
+}
+
+impl SliderFormat {
+    pub fn precision(precision: usize) -> Self {
+        Self {
+            precision,
+            ..default()
+        }
+    }
+
+    pub fn percent(precision: usize) -> Self {
+        Self {
+            precision,
+            percent: true,
+            ..default()
+        }
+    }
+
+    pub fn with_suffix(self, suffix: impl Into<String>) -> Self {
+        Self {
+            suffix: suffix.into(),
+            ..self
+        }
+    }
+
+    pub fn format(&self, value: f32) -> String {
+        match self.percent {
+            true => format!("{:.*}%", self.precision, value * 100.),
+            false => format!("{:.*}{}", self.precision, value, self.suffix),
+        }
+    }
+
+    /// Parses a typed value, ignoring the unit around it
+    pub fn parse(&self, text: &str) -> Option<f32> {
+        let text = text.trim();
+        let suffix = self.suffix.trim();
+        let text = match suffix.is_empty() {
+            true => text,
+            false => text.strip_suffix(suffix).unwrap_or(text),
+        };
+        let text =
+            text.trim_matches(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')));
+
+        let value = text.parse::<f32>().ok().filter(|value| value.is_finite())?;
+        match self.percent {
+            true => Some(value / 100.),
+            false => Some(value),
+        }
+    }
+}
+
 #[derive(Component, Clone, Debug, Reflect)]
 pub struct SliderConfig {
     label: Option<String>,
@@ -209,6 +668,14 @@ pub struct SliderConfig {
     initial_value: f32,
     show_current: bool,
     axis: SliderAxis,
+    step: f32,
+    bar_click: SliderBarClick,
+    format: SliderFormat,
+    #[reflect(ignore)]
+    formatter: Option<fn(f32) -> String>,
+    editable: bool,
+    scale: SliderScale,
+    high_value: Option<f32>,
 }
 
 impl SliderConfig {
@@ -234,6 +701,7 @@ impl SliderConfig {
             show_current,
             axis,
             label: SliderConfig::into_label(label),
+            ..default()
         }
     }
 
@@ -282,6 +750,63 @@ impl SliderConfig {
         panic!("Value must be between min and max!");
     }
 
+    /// Snaps the value to `min + n * step`. A step of 0 disables snapping.
+    pub fn with_step(self, step: f32) -> Self {
+        if step < 0. {
+            panic!("Slider step must not be negative! Step: {}", step);
+        }
+
+        Self { step, ..self }
+    }
+
+    pub fn with_bar_click(self, bar_click: SliderBarClick) -> Self {
+        Self { bar_click, ..self }
+    }
+
+    pub fn with_format(self, format: SliderFormat) -> Self {
+        Self { format, ..self }
+    }
+
+    /// Formats the readout with a custom function. Typed values are still parsed by the format.
+    pub fn with_formatter(self, formatter: fn(f32) -> String) -> Self {
+        Self {
+            formatter: formatter.into(),
+            ..self
+        }
+    }
+
+    /// Shows the current value as a label instead of a text input
+    pub fn read_only(self) -> Self {
+        Self {
+            editable: false,
+            ..self
+        }
+    }
+
+    pub fn with_scale(self, scale: SliderScale) -> Self {
+        if scale == SliderScale::Logarithmic && self.min <= 0. {
+            panic!(
+                "Logarithmic sliders need a positive range! Min: {}, Max: {}",
+                self.min, self.max
+            );
+        }
+
+        Self { scale, ..self }
+    }
+
+    /// Turns the slider into a range slider, from the initial value up to `high`.
+    /// The readout of range sliders is always read only.
+    pub fn with_range(self, high: f32) -> Self {
+        if high >= self.initial_value && high <= self.max {
+            return Self {
+                high_value: high.into(),
+                ..self
+            };
+        }
+
+        panic!("High value must be between the initial value and max!");
+    }
+
     fn into_label(label: Option<impl Into<String>>) -> Option<String> {
         if let Some(label) = label {
             label.into().into()
@@ -300,6 +825,13 @@ impl Default for SliderConfig {
             initial_value: 0.5,
             show_current: Default::default(),
             axis: Default::default(),
+            step: 0.,
+            bar_click: Default::default(),
+            format: Default::default(),
+            formatter: None,
+            editable: true,
+            scale: Default::default(),
+            high_value: None,
         }
     }
 }
@@ -308,22 +840,33 @@ impl Default for SliderConfig {
 #[reflect(Component)]
 pub struct Slider {
     pub ratio: f32,
+    /// Ratio of the high end of range sliders
+    pub high_ratio: Option<f32>,
     pub config: SliderConfig,
     slider_bar: Entity,
     drag_handle: Entity,
+    high_handle: Entity,
     readout_target: Option<Entity>,
     base_ratio: Option<f32>,
+    /// Ratio before snapping, so slow drags still add up
+    drag_ratio: f32,
+    /// Thumb moved by the current drag, undecided while stacked thumbs wait for a direction
+    drag_thumb: Option<SliderThumb>,
 }
 
 impl Default for Slider {
     fn default() -> Self {
         Self {
             ratio: Default::default(),
+            high_ratio: None,
             config: Default::default(),
             slider_bar: Entity::PLACEHOLDER,
             drag_handle: Entity::PLACEHOLDER,
+            high_handle: Entity::PLACEHOLDER,
             readout_target: None,
             base_ratio: None,
+            drag_ratio: 0.,
+            drag_thumb: None,
         }
     }
 }
@@ -332,12 +875,14 @@ impl Default for Slider {
 #[reflect(Component)]
 pub struct SliderDragHandle {
     pub slider: Entity,
+    pub thumb: SliderThumb,
 }
 
 impl Default for SliderDragHandle {
     fn default() -> Self {
         Self {
             slider: Entity::PLACEHOLDER,
+            thumb: Default::default(),
         }
     }
 }
@@ -358,16 +903,191 @@ impl Default for SliderBar {
 
 impl Slider {
     pub fn value(&self) -> f32 {
-        self.config.min.lerp(self.config.max, self.ratio)
+        self.snap_value(self.ratio_to_value(self.ratio))
     }
 
+    /// Sets the value, or the low value of range sliders
     pub fn set_value(&mut self, value: f32) {
         if value > self.config.max || value < self.config.min {
             warn!("Tried to set slider value outside of range");
             return;
         }
 
-        self.ratio = (value - self.config.min) / (self.config.max + (0. - self.config.min))
+        let ratio = self.snap_ratio(self.value_to_ratio(value));
+        self.set_thumb_ratio(SliderThumb::Low, ratio);
+    }
+
+    pub fn is_range(&self) -> bool {
+        self.high_ratio.is_some()
+    }
+
+    /// The low and high values of range sliders
+    pub fn range(&self) -> Option<(f32, f32)> {
+        self.high_ratio.map(|high_ratio| {
+            (
+                self.value(),
+                self.snap_value(self.ratio_to_value(high_ratio)),
+            )
+        })
+    }
+
+    pub fn set_range(&mut self, low: f32, high: f32) {
+        if !self.is_range() {
+            warn!("Tried to set the range of a single value slider");
+            return;
+        }
+        if low > high || low < self.config.min || high > self.config.max {
+            warn!("Tried to set slider range outside of range");
+            return;
+        }
+
+        self.ratio = self.snap_ratio(self.value_to_ratio(low));
+        self.high_ratio = self.snap_ratio(self.value_to_ratio(high)).into();
+    }
+
+    /// The current value as shown in the readout
+    pub fn readout(&self) -> String {
+        match self.range() {
+            Some((low, high)) => format!("{} - {}", self.format(low), self.format(high)),
+            None => self.format(self.value()),
+        }
+    }
+
+    fn format(&self, value: f32) -> String {
+        match self.config.formatter {
+            Some(formatter) => formatter(value),
+            None => self.config.format.format(value),
+        }
+    }
+
+    fn ratio_to_value(&self, ratio: f32) -> f32 {
+        let SliderConfig {
+            min, max, scale, ..
+        } = self.config;
+
+        match scale {
+            SliderScale::Linear => min.lerp(max, ratio),
+            SliderScale::Logarithmic => min * (max / min).powf(ratio),
+            SliderScale::Ease(ease) => min.lerp(max, Slider::eased_ratio(ratio, ease)),
+        }
+    }
+
+    fn value_to_ratio(&self, value: f32) -> f32 {
+        let SliderConfig {
+            min, max, scale, ..
+        } = self.config;
+        let value = value.clamp(min, max);
+
+        match scale {
+            SliderScale::Linear => (value - min) / (max - min),
+            SliderScale::Logarithmic => (value / min).ln() / (max / min).ln(),
+            SliderScale::Ease(ease) => {
+                let target = (value - min) / (max - min);
+                if target <= 0. || target >= 1. {
+                    return target;
+                }
+
+                // Eases have no inverse, bisect the curve instead
+                let (mut low, mut high) = (0., 1.);
+                for _ in 0..INVERSE_EASE_ITERATIONS {
+                    let ratio: f32 = (low + high) / 2.;
+                    if Slider::eased_ratio(ratio, ease) < target {
+                        low = ratio;
+                    } else {
+                        high = ratio;
+                    }
+                }
+
+                (low + high) / 2.
+            }
+        }
+    }
+
+    fn eased_ratio(ratio: f32, ease: Ease) -> f32 {
+        ratio.ease(ease).clamp(0., 1.)
+    }
+
+    /// Rounds the value to the closest step
+    fn snap_value(&self, value: f32) -> f32 {
+        let SliderConfig { min, max, step, .. } = self.config;
+        if step <= 0. {
+            return value;
+        }
+
+        (min + ((value - min) / step).round() * step).clamp(min, max)
+    }
+
+    /// Moves the ratio to the closest step
+    fn snap_ratio(&self, ratio: f32) -> f32 {
+        let ratio = ratio.clamp(0., 1.);
+        if self.config.step <= 0. {
+            return ratio;
+        }
+
+        self.value_to_ratio(self.snap_value(self.ratio_to_value(ratio)))
+    }
+
+    /// The ratio of the thumb after moving by a number of steps,
+    /// or by percents of the bar without a step size
+    fn stepped_ratio(&self, thumb: SliderThumb, steps: f32) -> f32 {
+        let SliderConfig { min, max, step, .. } = self.config;
+        let ratio = self.thumb_ratio(thumb);
+        if step <= 0. {
+            return (ratio + steps * KEYBOARD_STEP_RATIO).clamp(0., 1.);
+        }
+
+        let value = (self.ratio_to_value(ratio) + steps * step).clamp(min, max);
+        self.snap_ratio(self.value_to_ratio(value))
+    }
+
+    fn thumbs(&self) -> &'static [SliderThumb] {
+        match self.is_range() {
+            true => &[SliderThumb::Low, SliderThumb::High],
+            false => &[SliderThumb::Low],
+        }
+    }
+
+    fn thumb_handle(&self, thumb: SliderThumb) -> Entity {
+        match thumb {
+            SliderThumb::Low => self.drag_handle,
+            SliderThumb::High => self.high_handle,
+        }
+    }
+
+    fn thumb_ratio(&self, thumb: SliderThumb) -> f32 {
+        match thumb {
+            SliderThumb::Low => self.ratio,
+            SliderThumb::High => self.high_ratio.unwrap_or(1.),
+        }
+    }
+
+    /// Sets the ratio of a thumb, stopping at the other one so they never cross.
+    /// Returns whether the thumb moved.
+    fn set_thumb_ratio(&mut self, thumb: SliderThumb, ratio: f32) -> bool {
+        let previous = self.thumb_ratio(thumb);
+        match (thumb, self.high_ratio) {
+            (SliderThumb::Low, Some(high_ratio)) => self.ratio = ratio.min(high_ratio),
+            (SliderThumb::Low, None) => self.ratio = ratio,
+            (SliderThumb::High, Some(_)) => self.high_ratio = ratio.max(self.ratio).into(),
+            (SliderThumb::High, None) => return false,
+        }
+
+        self.thumb_ratio(thumb) != previous
+    }
+
+    /// The thumb a press on the bar moves, stacked handles split toward the press
+    fn closest_thumb(&self, ratio: f32) -> SliderThumb {
+        let Some(high_ratio) = self.high_ratio else {
+            return SliderThumb::Low;
+        };
+
+        let low_distance = (ratio - self.ratio).abs();
+        let high_distance = (ratio - high_ratio).abs();
+        match high_distance < low_distance || (high_distance == low_distance && ratio > high_ratio)
+        {
+            true => SliderThumb::High,
+            false => SliderThumb::Low,
+        }
     }
 
     fn base_tween() -> AnimationConfig {
@@ -379,31 +1099,41 @@ impl Slider {
     }
 
     fn horizontal_container() -> impl Bundle {
-        NodeBundle {
-            style: Style {
-                width: Val::Percent(100.),
-                height: Val::Px(20.),
-                justify_content: JustifyContent::Start,
-                align_items: AlignItems::Center,
-                margin: UiRect::all(Val::Px(5.)),
+        (
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Px(20.),
+                    justify_content: JustifyContent::Start,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
                 ..default()
             },
-            ..default()
-        }
+            TokenStyle {
+                margin: TokenRect::all(SpacingToken::Small).into(),
+                ..default()
+            },
+        )
     }
 
     fn vertical_container() -> impl Bundle {
-        NodeBundle {
-            style: Style {
-                height: Val::Percent(100.),
-                justify_content: JustifyContent::SpaceBetween,
-                align_items: AlignItems::Center,
-                margin: UiRect::all(Val::Px(5.)),
-                flex_direction: FlexDirection::Column,
+        (
+            NodeBundle {
+                style: Style {
+                    height: Val::Percent(100.),
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
                 ..default()
             },
-            ..default()
-        }
+            TokenStyle {
+                margin: TokenRect::all(SpacingToken::Small).into(),
+                ..default()
+            },
+        )
     }
 
     fn horizontal_bar_container() -> impl Bundle {
@@ -417,6 +1147,8 @@ impl Slider {
                 ..default()
             },
             Interaction::default(),
+            TrackedInteraction::default(),
+            RelativeCursorPosition::default(),
             Scrollable::default(),
         )
     }
@@ -432,8 +1164,11 @@ impl Slider {
                     border: UiRect::px(1., 1., 0., 1.),
                     ..default()
                 },
-                background_color: Color::DARK_GRAY.into(),
-                border_color: Color::GRAY.into(),
+                ..default()
+            },
+            TokenStyle {
+                background: ColorToken::Outline.into(),
+                border: ColorToken::OutlineVariant.into(),
                 ..default()
             },
         )
@@ -450,6 +1185,10 @@ impl Slider {
                 },
                 ..default()
             },
+            TokenStyle {
+                padding: TokenRect::left(SpacingToken::Small).into(),
+                ..default()
+            },
         )
     }
 
@@ -465,6 +1204,8 @@ impl Slider {
                 ..default()
             },
             Interaction::default(),
+            TrackedInteraction::default(),
+            RelativeCursorPosition::default(),
             Scrollable::default(),
         )
     }
@@ -481,21 +1222,27 @@ impl Slider {
                     border: UiRect::px(1., 1., 0., 1.),
                     ..default()
                 },
-                background_color: Color::DARK_GRAY.into(),
-                border_color: Color::GRAY.into(),
+                ..default()
+            },
+            TokenStyle {
+                background: ColorToken::Outline.into(),
+                border: ColorToken::OutlineVariant.into(),
                 ..default()
             },
         )
     }
 
-    fn handle_bundle(slider: Entity, axis: SliderAxis) -> impl Bundle {
+    fn handle_bundle(slider: Entity, axis: SliderAxis, thumb: SliderThumb) -> impl Bundle {
         let margin = match axis {
             SliderAxis::Horizontal => UiRect::top(Val::Px(-8.)),
             SliderAxis::Vertical => UiRect::left(Val::Px(-8.)),
         };
 
         (
-            Name::new("Handle"),
+            Name::new(match thumb {
+                SliderThumb::Low => "Handle",
+                SliderThumb::High => "High Handle",
+            }),
             ButtonBundle {
                 style: Style {
                     width: Val::Px(20.),
@@ -504,20 +1251,26 @@ impl Slider {
                     margin,
                     ..default()
                 },
-                background_color: Color::AQUAMARINE.into(),
-                border_color: Color::GRAY.into(),
                 ..default()
             },
             TrackedInteraction::default(),
-            InteractiveBackground {
-                highlight: Color::rgba(0., 1., 1., 0.8).into(),
+            InteractiveBackground::default(),
+            TokenStyle {
+                background: ColorToken::Accent.into(),
+                border: ColorToken::OutlineVariant.into(),
+                highlight: ColorToken::Highlight.into(),
                 ..default()
             },
             AnimatedInteraction::<InteractiveBackground> {
                 tween: Slider::base_tween(),
                 ..default()
             },
-            SliderDragHandle { slider },
+            SliderDragHandle { slider, thumb },
+            Focusable::default(),
+            match axis {
+                SliderAxis::Horizontal => CaptureNavigationAxis::Horizontal,
+                SliderAxis::Vertical => CaptureNavigationAxis::Vertical,
+            },
             Draggable::default(),
             Scrollable::default(),
         )
@@ -531,8 +1284,10 @@ pub trait UiSliderExt<'w, 's> {
 impl<'w, 's> UiSliderExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
     fn slider<'a>(&'a mut self, config: SliderConfig) -> UiBuilder<'w, 's, 'a, Entity> {
         let mut drag_handle: Entity = Entity::PLACEHOLDER;
+        let mut high_handle: Entity = Entity::PLACEHOLDER;
         let mut slider_bar: Entity = Entity::PLACEHOLDER;
         let mut readout_target: Option<Entity> = None;
+        let editable = config.editable && config.high_value.is_none();
 
         let mut input = match config.axis {
             SliderAxis::Horizontal => self.container(Slider::horizontal_container(), |slider| {
@@ -545,7 +1300,12 @@ impl<'w, 's> UiSliderExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
 
                     slider.label(LabelConfig {
                         label: label_string,
-                        margin: UiRect::px(5., 10., 0., 0.),
+                        spacing: TokenRect {
+                            left: SpacingToken::Small.into(),
+                            right: SpacingToken::Medium.into(),
+                            ..default()
+                        }
+                        .into(),
                         ..default()
                     });
                 }
@@ -559,8 +1319,21 @@ impl<'w, 's> UiSliderExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
                         |bar_container| {
                             bar_container.container(Slider::horizontal_bar(), |bar| {
                                 drag_handle = bar
-                                    .spawn(Slider::handle_bundle(input_id, SliderAxis::Horizontal))
+                                    .spawn(Slider::handle_bundle(
+                                        input_id,
+                                        SliderAxis::Horizontal,
+                                        SliderThumb::Low,
+                                    ))
                                     .id();
+                                if config.high_value.is_some() {
+                                    high_handle = bar
+                                        .spawn(Slider::handle_bundle(
+                                            input_id,
+                                            SliderAxis::Horizontal,
+                                            SliderThumb::High,
+                                        ))
+                                        .id();
+                                }
                             });
                         },
                     )
@@ -570,13 +1343,24 @@ impl<'w, 's> UiSliderExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
                     slider.container(
                         Slider::horizontal_readout_container(),
                         |readout_container| {
-                            readout_target = readout_container
-                                .label(LabelConfig {
-                                    margin: UiRect::left(Val::Px(5.)),
-                                    ..default()
-                                })
-                                .id()
-                                .into();
+                            readout_target = match editable {
+                                true => {
+                                    let mut text_input =
+                                        readout_container.text_input(TextInputConfig::default());
+                                    text_input
+                                        .style()
+                                        .width(Val::Auto)
+                                        .margin(UiRect::all(Val::Px(0.)))
+                                        .flex_grow(1.);
+                                    // The readout container spaces the input, not the tokens
+                                    text_input.insert(LockedStyleAttributes::from_vec(vec![
+                                        StylableAttribute::Margin,
+                                    ]));
+                                    text_input.id()
+                                }
+                                false => readout_container.label(LabelConfig::default()).id(),
+                            }
+                            .into();
                         },
                     );
                 }
@@ -585,13 +1369,24 @@ impl<'w, 's> UiSliderExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
                 let input_id = slider.id();
 
                 if config.show_current {
-                    readout_target = slider
-                        .label(LabelConfig {
-                            margin: UiRect::px(5., 5., 5., 0.),
-                            ..default()
-                        })
-                        .id()
-                        .into();
+                    readout_target = match editable {
+                        true => {
+                            let mut text_input = slider.text_input(TextInputConfig::default());
+                            text_input.style().width(Val::Px(50.));
+                            text_input.id()
+                        }
+                        false => slider
+                            .label(LabelConfig {
+                                spacing: TokenRect {
+                                    top: SpacingToken::Small.into(),
+                                    ..TokenRect::horizontal(SpacingToken::Small)
+                                }
+                                .into(),
+                                ..default()
+                            })
+                            .id(),
+                    }
+                    .into();
                 }
 
                 slider_bar = slider
@@ -603,8 +1398,21 @@ impl<'w, 's> UiSliderExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
                         |bar_container| {
                             bar_container.container(Slider::vertical_bar(), |bar| {
                                 drag_handle = bar
-                                    .spawn(Slider::handle_bundle(input_id, SliderAxis::Vertical))
+                                    .spawn(Slider::handle_bundle(
+                                        input_id,
+                                        SliderAxis::Vertical,
+                                        SliderThumb::Low,
+                                    ))
                                     .id();
+                                if config.high_value.is_some() {
+                                    high_handle = bar
+                                        .spawn(Slider::handle_bundle(
+                                            input_id,
+                                            SliderAxis::Vertical,
+                                            SliderThumb::High,
+                                        ))
+                                        .id();
+                                }
                             });
                         },
                     )
@@ -617,22 +1425,79 @@ impl<'w, 's> UiSliderExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
 
                     slider.label(LabelConfig {
                         label: label_string,
-                        margin: UiRect::px(5., 5., 0., 5.),
+                        spacing: TokenRect {
+                            bottom: SpacingToken::Small.into(),
+                            ..TokenRect::horizontal(SpacingToken::Small)
+                        }
+                        .into(),
                         ..default()
                     });
                 }
             }),
         };
 
-        input.insert(Slider {
-            ratio: (config.initial_value - config.min) / (config.max + (0. - config.min)),
+        let mut slider = Slider {
             config,
             slider_bar,
             drag_handle,
+            high_handle,
             readout_target,
             ..default()
-        });
+        };
+        slider.set_value(slider.config.initial_value);
+        if let Some(high_value) = slider.config.high_value {
+            slider.high_ratio = slider.snap_ratio(slider.value_to_ratio(high_value)).into();
+        }
+        input.insert(slider);
 
         input
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slider(min: f32, max: f32, scale: SliderScale) -> Slider {
+        Slider {
+            config: SliderConfig::horizontal(None::<String>, min, max, min, false)
+                .with_scale(scale),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn ratio_round_trips_through_value() {
+        let sliders = [
+            slider(-10., 10., SliderScale::Linear),
+            slider(20., 20000., SliderScale::Logarithmic),
+            slider(0., 10., SliderScale::Ease(Ease::InQuad)),
+            slider(0., 10., SliderScale::Ease(Ease::InOutCubic)),
+        ];
+
+        for slider in &sliders {
+            for step in 0..=20 {
+                let ratio = step as f32 / 20.;
+                let round_trip = slider.value_to_ratio(slider.ratio_to_value(ratio));
+                assert!(
+                    (round_trip - ratio).abs() < 1e-3,
+                    "{:?}: {} became {}",
+                    slider.config.scale,
+                    ratio,
+                    round_trip
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn overshooting_eases_stay_in_range() {
+        for ease in [Ease::OutBack, Ease::InOutElastic] {
+            let slider = slider(0., 10., SliderScale::Ease(ease));
+            for step in 0..=100 {
+                let value = slider.ratio_to_value(step as f32 / 100.);
+                assert!((0. ..=10.).contains(&value), "{:?}: {}", ease, value);
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
 use crate::{
+    theme::tokens::{ColorToken, ThemeTokens, TokenStyle},
     ui_builder::{UiBuilder, UiBuilderExt},
     ui_style::{SetBackgroundColorExt, SetEntityVisiblityExt, UiStyleExt},
     FluxInteraction, FluxInteractionStopwatch, FluxInteractionUpdate, TrackedInteraction,
@@ -219,10 +220,16 @@ fn update_submenu_state(
     }
 }
 
-fn update_submenu_style(q_submenus: Query<(Entity, Ref<Submenu>)>, mut commands: Commands) {
+fn update_submenu_style(
+    q_submenus: Query<(Entity, Ref<Submenu>)>,
+    tokens: Res<ThemeTokens>,
+    mut commands: Commands,
+) {
     for (entity, submenu) in &q_submenus {
         if submenu.is_open {
-            commands.style(entity).background_color(Color::DARK_GRAY);
+            commands
+                .style(entity)
+                .background_color(tokens.color(ColorToken::Active));
         } else if submenu.is_changed() {
             commands.style(entity).background_color(Color::NONE);
         }
@@ -318,13 +325,16 @@ impl SubmenuContainer {
                     ..default()
                 },
                 z_index: ZIndex::Global(MENU_CONTAINER_Z_INDEX),
-                background_color: Color::rgb(0.1, 0.1, 0.1).into(),
-                border_color: Color::ANTIQUE_WHITE.into(),
                 focus_policy: bevy::ui::FocusPolicy::Block,
                 ..default()
             },
             Interaction::default(),
             TrackedInteraction::default(),
+            TokenStyle {
+                background: ColorToken::Surface.into(),
+                border: ColorToken::OnSurface.into(),
+                ..default()
+            },
         )
     }
 }
@@ -4,71 +4,119 @@ use sickle_math::ease::Ease;
 use crate::{
     animated_interaction::{AnimatedInteraction, AnimationConfig},
     drag_interaction::{DragState, Draggable, DraggableUpdate},
-    interactions::InteractiveBackground,
+    focus_interaction::{ConfineFocus, Focusable, FocusedEntity},
+    interactions::{set_pseudo_state, InteractiveBackground, PseudoState, PseudoStates},
+    theme::{
+        tokens::{ColorToken, TokenStyle},
+        ComponentThemePlugin,
+    },
     ui_builder::{UiBuilder, UiBuilderExt, UiContextRoot},
+    ui_callbacks::{
+        report_ui_callbacks, UiCallbackEvent, UiCallbackTrigger, UiCallbackTriggered,
+        UiCallbackUpdate,
+    },
     ui_style::{
-        SetBackgroundColorExt, SetFluxInteractionExt, SetNodeLeftExt, SetNodeOverflowExt,
-        SetNodePositionTypeExt, SetNodeShowHideExt, SetZIndexExt, UiStyleExt,
+        SetFluxInteractionExt, SetNodeHeightExt, SetNodeLeftExt, SetNodeMarginExt,
+        SetNodeOverflowExt, SetNodePositionTypeExt, SetNodeShowHideExt, SetNodeWidthExt,
+        SetZIndexExt, UiStyleExt,
     },
-    TrackedInteraction,
+    DoubleClicked, FluxInteraction, FluxInteractionUpdate, TrackedInteraction,
 };
 
 use super::{
     context_menu::ContextMenuUpdate,
     floating_panel::{FloatingPanel, FloatingPanelUpdate, UpdateFloatingPanelPanelId},
-    panel::Panel,
+    panel::{Panel, PanelClosed},
     prelude::{
         ContextMenuGenerator, FloatingPanelConfig, FloatingPanelLayout, GenerateContextMenu,
         LabelConfig, MenuItem, MenuItemConfig, MenuItemUpdate, ReflectContextMenuGenerator,
-        UiContainerExt, UiFloatingPanelExt, UiLabelExt, UiMenuItemExt, UiPanelExt, UiScrollViewExt,
+        SetLabelTextExt, TextInput, TextInputConfig, TextInputSubmitted, UiContainerExt,
+        UiFloatingPanelExt, UiLabelExt, UiMenuItemExt, UiPanelExt, UiScrollViewExt, UiTextInputExt,
     },
     sized_zone::SizedZonePreUpdate,
+    text_input::TextInputUpdate,
 };
 
+const RENAME_INPUT_MIN_WIDTH: f32 = 100.;
+
 pub struct TabContainerPlugin;
 
 impl Plugin for TabContainerPlugin {
     fn build(&self, app: &mut App) {
-        app.configure_sets(
-            Update,
-            TabContainerUpdate
-                .after(DraggableUpdate)
-                .before(FloatingPanelUpdate),
-        )
-        .register_type::<Tab>()
-        .add_systems(
-            PreUpdate,
-            (
-                dock_panel_in_tab_container,
-                popout_panel_from_tab.before(SizedZonePreUpdate),
-            ),
-        )
-        .add_systems(
-            Update,
-            (
-                close_tab_on_context_menu_press,
-                popout_tab_on_context_menu_press,
+        app.add_plugins(ComponentThemePlugin::<Tab>::default())
+            .configure_sets(
+                Update,
+                TabContainerUpdate
+                    .after(DraggableUpdate)
+                    .before(FloatingPanelUpdate),
             )
-                .after(MenuItemUpdate)
-                .before(ContextMenuUpdate)
-                .before(TabContainerUpdate),
-        )
-        .add_systems(
-            Update,
-            (
-                update_tab_container_on_tab_press,
-                update_tab_container_on_change,
-                handle_tab_dragging,
+            .register_type::<Tab>()
+            .register_type::<TabRenameInput>()
+            .add_event::<TabActivated>()
+            .add_event::<UiCallbackTriggered>()
+            .add_systems(
+                Update,
+                report_ui_callbacks::<TabActivated>
+                    .after(TabContainerUpdate)
+                    .before(UiCallbackUpdate),
             )
-                .chain()
-                .in_set(TabContainerUpdate),
-        );
+            .add_systems(
+                PreUpdate,
+                (
+                    dock_panel_in_tab_container,
+                    popout_panel_from_tab.before(SizedZonePreUpdate),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    close_tab_on_context_menu_press,
+                    popout_tab_on_context_menu_press,
+                )
+                    .after(MenuItemUpdate)
+                    .before(ContextMenuUpdate)
+                    .before(TabContainerUpdate),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_tab_container_on_tab_press,
+                    update_tab_container_on_change,
+                    handle_tab_dragging,
+                )
+                    .chain()
+                    .in_set(TabContainerUpdate),
+            )
+            .add_systems(
+                Update,
+                (
+                    rename_tab_on_double_click
+                        .after(FluxInteractionUpdate)
+                        .before(TextInputUpdate),
+                    finish_tab_rename.after(TextInputUpdate),
+                ),
+            );
     }
 }
 
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct TabContainerUpdate;
 
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TabActivated {
+    pub entity: Entity,
+    pub tab: Entity,
+    pub index: usize,
+}
+
+impl UiCallbackEvent for TabActivated {
+    const TRIGGER: UiCallbackTrigger = UiCallbackTrigger::Change;
+
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
 fn dock_panel_in_tab_container(
     mut q_docking_panels: Query<
         (Entity, &mut TabContainer, &DockFloatingPanel),
@@ -76,6 +124,7 @@ fn dock_panel_in_tab_container(
     >,
     q_floating_panel: Query<&FloatingPanel>,
     q_panel: Query<&Panel>,
+    mut e_activated: EventWriter<TabActivated>,
     mut commands: Commands,
 ) {
     for (container_id, mut tab_container, dock_ref) in &mut q_docking_panels {
@@ -102,24 +151,27 @@ fn dock_panel_in_tab_container(
         let bar_id = tab_container.bar;
         let viewport_id = tab_container.viewport;
 
-        commands.ui_builder(bar_id).container(
-            (
-                Name::new(format!("Tab [{}]", panel.title())),
-                TabContainer::tab(),
-                Tab {
-                    container: container_id,
-                    bar: bar_id,
-                    panel: panel_id,
-                    ..default()
+        let tab = commands
+            .ui_builder(bar_id)
+            .container(
+                (
+                    Name::new(format!("Tab [{}]", panel.title())),
+                    TabContainer::tab(),
+                    Tab {
+                        container: container_id,
+                        bar: bar_id,
+                        panel: panel_id,
+                        ..default()
+                    },
+                ),
+                |container| {
+                    container.label(LabelConfig {
+                        label: panel.title(),
+                        ..default()
+                    });
                 },
-            ),
-            |container| {
-                container.label(LabelConfig {
-                    label: panel.title(),
-                    ..default()
-                });
-            },
-        );
+            )
+            .id();
 
         commands.entity(viewport_id).add_child(panel_id);
         commands.entity(dock_ref.floating_panel).despawn_recursive();
@@ -127,6 +179,11 @@ fn dock_panel_in_tab_container(
 
         tab_container.tab_count += 1;
         tab_container.active = tab_container.tab_count - 1;
+        e_activated.send(TabActivated {
+            entity: container_id,
+            tab,
+            index: tab_container.active,
+        });
     }
 }
 
@@ -209,7 +266,10 @@ fn popout_panel_from_tab(
 fn close_tab_on_context_menu_press(
     q_menu_items: Query<(Entity, &CloseTabContextMenu, &MenuItem), Changed<MenuItem>>,
     q_tab: Query<&Tab>,
+    q_children: Query<&Children>,
     mut q_tab_container: Query<&mut TabContainer>,
+    mut e_closed: EventWriter<PanelClosed>,
+    mut e_activated: EventWriter<TabActivated>,
     mut commands: Commands,
 ) {
     for (entity, context_menu, menu_item) in &q_menu_items {
@@ -235,6 +295,21 @@ fn close_tab_on_context_menu_press(
                 false => 0,
             };
 
+            if let Some((tab, index)) = q_children
+                .get(tab_container.bar)
+                .ok()
+                .and_then(|tabs| tab_container.remove_tab(tabs, context_menu.tab))
+            {
+                e_activated.send(TabActivated {
+                    entity: tab_contaier_id,
+                    tab,
+                    index,
+                });
+            }
+
+            e_closed.send(PanelClosed {
+                entity: tab_data.panel,
+            });
             commands.entity(context_menu.tab).despawn_recursive();
             commands.entity(tab_data.panel).despawn_recursive();
         }
@@ -274,14 +349,106 @@ fn popout_tab_on_context_menu_press(
     }
 }
 
+fn rename_tab_on_double_click(
+    mut e_double_clicked: EventReader<DoubleClicked>,
+    q_tab: Query<(&Tab, &Children)>,
+    q_label: Query<&Node, With<LabelConfig>>,
+    q_rename_input: Query<&TabRenameInput>,
+    q_panel: Query<&Panel>,
+    mut focused: ResMut<FocusedEntity>,
+    mut commands: Commands,
+) {
+    for event in e_double_clicked.read() {
+        let Ok((tab, children)) = q_tab.get(event.entity) else {
+            continue;
+        };
+
+        if q_rename_input.iter().any(|input| input.tab == event.entity) {
+            continue;
+        }
+
+        let Some((label, label_node)) = children
+            .iter()
+            .find_map(|child| q_label.get(*child).ok().map(|node| (*child, node)))
+        else {
+            warn!("Failed to rename tab {:?}: Missing label", event.entity);
+            continue;
+        };
+
+        let Ok(panel) = q_panel.get(tab.panel) else {
+            warn!(
+                "Failed to rename tab {:?}: {:?} is not a Panel",
+                event.entity, tab.panel
+            );
+            continue;
+        };
+
+        let width = label_node.size().x.max(RENAME_INPUT_MIN_WIDTH);
+        let mut tab_builder = commands.ui_builder(event.entity);
+        let mut input = tab_builder.text_input(TextInputConfig::from(panel.title()));
+        input.insert(TabRenameInput {
+            tab: event.entity,
+            label,
+        });
+        input
+            .style()
+            .width(Val::Px(width))
+            .height(Val::Px(20.))
+            .margin(UiRect::all(Val::Px(0.)));
+
+        focused.0 = input.id().into();
+        commands.style(label).render(false);
+    }
+}
+
+/// Applies the title on submit, cancels the rename once the input loses focus
+fn finish_tab_rename(
+    mut e_submitted: EventReader<TextInputSubmitted>,
+    q_rename_input: Query<(Entity, &TabRenameInput, &TextInput)>,
+    q_tab: Query<&Tab>,
+    mut q_panel: Query<&mut Panel>,
+    focused: Res<FocusedEntity>,
+    mut commands: Commands,
+) {
+    let submitted: Vec<Entity> = e_submitted.read().map(|event| event.entity).collect();
+
+    for (entity, rename_input, input) in &q_rename_input {
+        let title = input.value().trim();
+        let submit = submitted.contains(&entity);
+        if !submit && focused.0 == Some(entity) {
+            continue;
+        }
+
+        if submit && !title.is_empty() {
+            if let Ok(tab) = q_tab.get(rename_input.tab) {
+                if let Ok(mut panel) = q_panel.get_mut(tab.panel) {
+                    panel.title = title.into();
+                    commands
+                        .entity(tab.panel)
+                        .insert(Name::new(format!("Panel [{}]", title)));
+                }
+            }
+
+            commands
+                .entity(rename_input.tab)
+                .insert(Name::new(format!("Tab [{}]", title)));
+            commands.entity(rename_input.label).set_label_text(title);
+        }
+
+        commands.entity(entity).despawn_recursive();
+        commands.style(rename_input.label).render(true);
+    }
+}
+
 fn update_tab_container_on_tab_press(
-    q_tabs: Query<(Entity, &Tab, &Interaction), Changed<Interaction>>,
+    q_tabs: Query<(Entity, &Tab, &FluxInteraction), Changed<FluxInteraction>>,
     q_tab: Query<Entity, With<Tab>>,
     q_children: Query<&Children>,
     mut q_tab_container: Query<&mut TabContainer>,
+    mut e_activated: EventWriter<TabActivated>,
 ) {
     for (tab_entity, tab, interaction) in &q_tabs {
-        if *interaction == Interaction::Pressed {
+        if *interaction == FluxInteraction::Pressed {
             let Ok(mut tab_container) = q_tab_container.get_mut(tab.container) else {
                 continue;
             };
@@ -292,8 +459,13 @@ fn update_tab_container_on_tab_press(
 
             for (i, id) in tabs.iter().enumerate() {
                 if let Ok(_) = q_tab.get(*id) {
-                    if *id == tab_entity {
+                    if *id == tab_entity && tab_container.active != i {
                         tab_container.active = i;
+                        e_activated.send(TabActivated {
+                            entity: tab.container,
+                            tab: tab_entity,
+                            index: i,
+                        });
                     }
                 }
             }
@@ -303,7 +475,7 @@ fn update_tab_container_on_tab_press(
 
 fn update_tab_container_on_change(
     q_tab_containers: Query<&TabContainer, Changed<TabContainer>>,
-    q_tab: Query<(Entity, &Tab), With<Tab>>,
+    mut q_tab: Query<(Entity, &Tab, &mut PseudoStates)>,
     q_children: Query<&Children>,
     mut commands: Commands,
 ) {
@@ -312,18 +484,19 @@ fn update_tab_container_on_change(
             continue;
         };
 
-        let flux_enabled = tabs.iter().filter(|tab| q_tab.get(**tab).is_ok()).count() > 1;
+        let flux_enabled = tabs.iter().filter(|tab| q_tab.contains(**tab)).count() > 1;
         for (i, id) in tabs.iter().enumerate() {
-            if let Ok((tab_entity, tab)) = q_tab.get(*id) {
+            if let Ok((tab_entity, tab, mut pseudo_states)) = q_tab.get_mut(*id) {
                 commands
                     .style(tab_entity)
                     .flux_interaction_enabled(flux_enabled);
 
-                if i == tab_container.active {
-                    commands.style(tab_entity).background_color(Color::GRAY);
+                let active = i == tab_container.active;
+                set_pseudo_state(&mut pseudo_states, PseudoState::Active, active);
+
+                if active {
                     commands.style(tab.panel).show();
                 } else {
-                    commands.style(tab_entity).background_color(Color::NONE);
                     commands.style(tab.panel).hide();
                 }
             }
@@ -391,15 +564,20 @@ fn handle_tab_dragging(
                     transform.translation.truncate().x - (node.size().x / 2.) + bar_half_width;
                 let placeholder = commands
                     .ui_builder(container.bar)
-                    .spawn(NodeBundle {
-                        style: Style {
-                            width: Val::Px(node.size().x * 1.1),
-                            height: Val::Px(node.size().y),
+                    .spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Px(node.size().x * 1.1),
+                                height: Val::Px(node.size().y),
+                                ..default()
+                            },
                             ..default()
                         },
-                        background_color: Color::NAVY.into(),
-                        ..default()
-                    })
+                        TokenStyle {
+                            background: ColorToken::Highlight.into(),
+                            ..default()
+                        },
+                    ))
                     .id();
 
                 commands
@@ -628,6 +806,23 @@ impl ContextMenuGenerator for Tab {
     }
 }
 
+/// Text input editing the title of a tab, replaces the tab's label while open
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct TabRenameInput {
+    tab: Entity,
+    label: Entity,
+}
+
+impl Default for TabRenameInput {
+    fn default() -> Self {
+        Self {
+            tab: Entity::PLACEHOLDER,
+            label: Entity::PLACEHOLDER,
+        }
+    }
+}
+
 #[derive(Component)]
 #[component(storage = "SparseSet")]
 struct PopoutPanelFromTabContainer {
@@ -727,6 +922,27 @@ impl TabContainer {
     pub fn set_active(&mut self, active: usize) {
         self.active = active;
     }
+
+    /// Keeps the active index in place for a tab that is being removed from the bar.
+    /// Returns the tab that becomes active and its index when the removed tab was active.
+    fn remove_tab(&mut self, tabs: &Children, tab: Entity) -> Option<(Entity, usize)> {
+        let index = tabs.iter().position(|id| *id == tab)?;
+        let was_active = index == self.active;
+        let remaining = tabs.len() - 1;
+
+        if index < self.active {
+            self.active -= 1;
+        } else if self.active >= remaining {
+            self.active = remaining.saturating_sub(1);
+        }
+
+        if !was_active {
+            return None;
+        }
+
+        let active_tab = tabs.iter().filter(|id| **id != tab).nth(self.active)?;
+        Some((*active_tab, self.active))
+    }
 }
 
 impl TabContainer {
@@ -765,10 +981,14 @@ impl TabContainer {
                     overflow: Overflow::clip(),
                     ..default()
                 },
-                border_color: Color::DARK_GRAY.into(),
                 ..default()
             },
             Interaction::default(),
+            ConfineFocus,
+            TokenStyle {
+                border: ColorToken::Outline.into(),
+                ..default()
+            },
         )
     }
 
@@ -780,13 +1000,17 @@ impl TabContainer {
                     border: UiRect::horizontal(Val::Px(1.)),
                     ..default()
                 },
-                border_color: Color::DARK_GRAY.into(),
                 ..default()
             },
             Interaction::default(),
             TrackedInteraction::default(),
-            InteractiveBackground {
-                highlight: Color::rgba(0.9, 0.8, 0.7, 0.5).into(),
+            Focusable::default(),
+            PseudoStates::default(),
+            InteractiveBackground::default(),
+            TokenStyle {
+                border: ColorToken::Outline.into(),
+                highlight: ColorToken::Highlight.into(),
+                active: ColorToken::SurfaceRaised.into(),
                 ..default()
             },
             AnimatedInteraction::<InteractiveBackground> {
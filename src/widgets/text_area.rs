@@ -0,0 +1,931 @@
+use std::ops::Range;
+
+use bevy::{ecs::system::SystemParam, prelude::*, ui::FocusPolicy};
+
+use crate::{
+    focus_interaction::{CaptureKeyboardInput, Focusable, FocusedEntity},
+    input_extension::SymmetricKeysExt,
+    interactions::PseudoStates,
+    node_window::NodeWindow,
+    scroll_interaction::ScrollAxis,
+    theme::{
+        tokens::{
+            ColorToken, FontSizeToken, FontToken, SpacingToken, ThemeTokens, TokenRect, TokenStyle,
+        },
+        ComponentThemePlugin,
+    },
+    ui_builder::UiBuilder,
+    ui_callbacks::{
+        report_ui_callbacks, UiCallbackEvent, UiCallbackTrigger, UiCallbackTriggered,
+        UiCallbackUpdate,
+    },
+    DoubleClicked, FluxInteraction, TrackedInteraction,
+};
+
+use super::{
+    prelude::{UiContainerExt, UiScrollViewExt},
+    scroll_view::ScrollView,
+    text_input::{
+        char_offsets, line_height, update_text_edit_caret, update_text_edit_focus, TextEdit,
+        TextEditEvents, TextEditInput, TextEditWidget, TextInputUpdate, TextInputWindow, BORDER,
+        CARET_WIDTH, PADDING,
+    },
+};
+
+pub struct TextAreaPlugin;
+
+impl Plugin for TextAreaPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(ComponentThemePlugin::<TextArea>::default())
+            .register_type::<TextArea>()
+            .add_event::<TextAreaChanged>()
+            .add_event::<UiCallbackTriggered>()
+            .add_systems(
+                Update,
+                report_ui_callbacks::<TextAreaChanged>
+                    .after(TextInputUpdate)
+                    .before(UiCallbackUpdate),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_text_edit_focus::<TextArea>,
+                    update_text_area_on_press,
+                    update_text_area_on_keyboard,
+                    update_text_area_display,
+                    update_text_edit_caret::<TextArea>,
+                )
+                    .chain()
+                    .in_set(TextInputUpdate),
+            );
+    }
+}
+
+/// Sent when the value is edited by the user
+#[derive(Event, Clone, Debug)]
+pub struct TextAreaChanged {
+    pub entity: Entity,
+    pub value: String,
+}
+
+impl UiCallbackEvent for TextAreaChanged {
+    const TRIGGER: UiCallbackTrigger = UiCallbackTrigger::Change;
+
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+#[derive(Clone, Debug, Reflect)]
+pub struct TextAreaConfig {
+    pub value: String,
+    /// Shown while the value is empty
+    pub placeholder: String,
+    /// Maximum number of characters
+    pub max_length: Option<usize>,
+    /// Soft wraps long lines like a label, `FlexWrap::NoWrap` scrolls horizontally instead
+    pub wrap: FlexWrap,
+    /// Number of visible lines
+    pub rows: usize,
+}
+
+impl Default for TextAreaConfig {
+    fn default() -> Self {
+        Self {
+            value: Default::default(),
+            placeholder: Default::default(),
+            max_length: None,
+            wrap: FlexWrap::Wrap,
+            rows: 5,
+        }
+    }
+}
+
+impl TextAreaConfig {
+    pub fn from(value: impl Into<String>) -> TextAreaConfig {
+        TextAreaConfig {
+            value: value.into(),
+            ..default()
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct TextArea {
+    pub config: TextAreaConfig,
+    edit: TextEdit,
+    /// Caret x kept while moving vertically through shorter lines
+    preferred_x: Option<f32>,
+    scroll_to_caret: bool,
+    caret_moved_at: f32,
+    scroll_view: Entity,
+    lines_node: Entity,
+    text_node: Entity,
+    caret_node: Entity,
+    selection_nodes: [Entity; 3],
+}
+
+impl Default for TextArea {
+    fn default() -> Self {
+        Self {
+            config: Default::default(),
+            edit: Default::default(),
+            preferred_x: None,
+            scroll_to_caret: false,
+            caret_moved_at: 0.,
+            scroll_view: Entity::PLACEHOLDER,
+            lines_node: Entity::PLACEHOLDER,
+            text_node: Entity::PLACEHOLDER,
+            caret_node: Entity::PLACEHOLDER,
+            selection_nodes: [Entity::PLACEHOLDER; 3],
+        }
+    }
+}
+
+impl TextArea {
+    pub fn value(&self) -> &str {
+        self.edit.value()
+    }
+
+    /// Byte range of the selected text, if any
+    pub fn selection(&self) -> Option<Range<usize>> {
+        self.edit.selection()
+    }
+
+    /// Zero-based line and column (in chars) of the caret
+    pub fn line_column(&self) -> (usize, usize) {
+        self.edit.line_column()
+    }
+
+    /// Replaces the value, cut to the max length. Does not send [`TextAreaChanged`].
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        let value: String = value.into();
+        let value = match self.config.max_length {
+            Some(max_length) => value.chars().take(max_length).collect(),
+            None => value,
+        };
+
+        self.edit.set_value(value);
+        self.preferred_x = None;
+    }
+
+    fn moved_caret(&mut self, now: f32) {
+        self.caret_moved_at = now;
+        self.scroll_to_caret = true;
+    }
+
+    fn frame() -> impl Bundle {
+        (
+            Name::new("Text Area"),
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    border: UiRect::all(Val::Px(BORDER)),
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                focus_policy: FocusPolicy::Block,
+                ..default()
+            },
+            Interaction::default(),
+            TrackedInteraction::default(),
+            Focusable::default(),
+            CaptureKeyboardInput,
+            PseudoStates::default(),
+            TokenStyle {
+                background: ColorToken::Input.into(),
+                border: ColorToken::Outline.into(),
+                margin: TokenRect::all(SpacingToken::Small).into(),
+                ..default()
+            },
+        )
+    }
+
+    fn lines_bundle() -> impl Bundle {
+        (
+            Name::new("Lines"),
+            NodeBundle::default(),
+            TokenStyle {
+                margin: TokenRect::all(PADDING).into(),
+                ..default()
+            },
+        )
+    }
+
+    fn selection_bundle() -> impl Bundle {
+        (
+            Name::new("Selection"),
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            TokenStyle {
+                background: ColorToken::Highlight.into(),
+                ..default()
+            },
+        )
+    }
+
+    fn text_bundle() -> impl Bundle {
+        (
+            Name::new("Text"),
+            TextBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.),
+                    top: Val::Px(0.),
+                    ..default()
+                },
+                // Soft wraps are inserted as line breaks, so the caret knows where they are
+                text: Text::default().with_no_wrap(),
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+        )
+    }
+
+    fn caret_bundle() -> impl Bundle {
+        (
+            Name::new("Caret"),
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Px(CARET_WIDTH),
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            TokenStyle {
+                background: ColorToken::OnInput.into(),
+                ..default()
+            },
+        )
+    }
+}
+
+impl TextEditWidget for TextArea {
+    fn edit(&self) -> &TextEdit {
+        &self.edit
+    }
+
+    fn edit_mut(&mut self) -> &mut TextEdit {
+        &mut self.edit
+    }
+
+    fn caret_moved_at(&self) -> f32 {
+        self.caret_moved_at
+    }
+
+    fn set_caret_moved_at(&mut self, now: f32) {
+        self.caret_moved_at = now;
+    }
+
+    fn caret_node(&self) -> Entity {
+        self.caret_node
+    }
+
+    fn selection_nodes(&self) -> &[Entity] {
+        &self.selection_nodes
+    }
+}
+
+/// A visual line, either a line of the value or the part of it that fits the width
+struct TextAreaLine {
+    /// Byte range in the displayed text, without the line break
+    range: Range<usize>,
+    /// X offset of every char boundary in the line
+    offsets: Vec<f32>,
+    /// The line continues on the next one, its end is the start of the next line
+    soft_wrapped: bool,
+}
+
+impl TextAreaLine {
+    fn width(&self) -> f32 {
+        self.offsets.last().copied().unwrap_or_default()
+    }
+}
+
+/// The displayed text of an area broken into visual lines
+struct TextAreaLayout {
+    text: String,
+    placeholder: bool,
+    lines: Vec<TextAreaLine>,
+    line_height: f32,
+    /// Byte range of the IME composition in the displayed text
+    composition: Range<usize>,
+    /// Byte index of the caret in the displayed text
+    caret: usize,
+}
+
+impl TextAreaLayout {
+    fn new(area: &TextArea, wrap_width: Option<f32>, font: Option<&Font>, font_size: f32) -> Self {
+        let edit = &area.edit;
+        let caret = edit.caret();
+        let composition = edit.composition().unwrap_or_default();
+        let placeholder = edit.value().is_empty() && composition.is_empty();
+        let text = match placeholder {
+            true => area.config.placeholder.clone(),
+            false => {
+                let (before, after) = edit.value().split_at(caret);
+                format!("{}{}{}", before, composition, after)
+            }
+        };
+
+        let line_height = line_height(font, font_size);
+
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for hard_line in text.split('\n') {
+            wrap_line(&mut lines, hard_line, start, wrap_width, font, font_size);
+            start += hard_line.len() + 1;
+        }
+
+        Self {
+            text,
+            placeholder,
+            lines,
+            line_height,
+            composition: match placeholder {
+                true => 0..0,
+                false => caret..caret + composition.len(),
+            },
+            caret: match placeholder {
+                true => 0,
+                false => caret + composition.len(),
+            },
+        }
+    }
+
+    /// The displayed text with the soft wraps as line breaks
+    fn wrapped_text(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| &self.text[line.range.clone()])
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn width(&self) -> f32 {
+        self.lines
+            .iter()
+            .map(TextAreaLine::width)
+            .fold(0., f32::max)
+    }
+
+    fn height(&self) -> f32 {
+        self.lines.len().max(1) as f32 * self.line_height
+    }
+
+    /// The visual line of a display index. At a soft wrap it is the start of the next line.
+    fn line_of(&self, index: usize) -> usize {
+        self.lines
+            .iter()
+            .rposition(|line| line.range.start <= index)
+            .unwrap_or(0)
+    }
+
+    fn position(&self, index: usize) -> Vec2 {
+        let line_index = self.line_of(index);
+        let Some(line) = self.lines.get(line_index) else {
+            return Vec2::ZERO;
+        };
+
+        let end = index.clamp(line.range.start, line.range.end);
+        let char_index = self.text[line.range.start..end].chars().count();
+
+        Vec2::new(
+            line.offsets.get(char_index).copied().unwrap_or_default(),
+            line_index as f32 * self.line_height,
+        )
+    }
+
+    /// Display index of the char boundary nearest to `x` in a line
+    fn index_in_line(&self, line_index: usize, x: f32) -> usize {
+        let Some(line) = self.lines.get(line_index) else {
+            return self.text.len();
+        };
+
+        // The end of a wrapped line is shown at the start of the next one
+        let boundaries = match line.soft_wrapped {
+            true => line.offsets.len() - 1,
+            false => line.offsets.len(),
+        };
+        let char_index = line
+            .offsets
+            .iter()
+            .take(boundaries)
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - x).abs().total_cmp(&(*b - x).abs()))
+            .map_or(0, |(index, _)| index);
+
+        self.text[line.range.clone()]
+            .char_indices()
+            .nth(char_index)
+            .map_or(line.range.end, |(index, _)| line.range.start + index)
+    }
+
+    /// Display index of the last caret position in a line
+    fn line_end(&self, line_index: usize) -> usize {
+        let Some(line) = self.lines.get(line_index) else {
+            return self.text.len();
+        };
+
+        match line.soft_wrapped {
+            true => self.text[line.range.clone()]
+                .char_indices()
+                .next_back()
+                .map_or(line.range.end, |(index, _)| line.range.start + index),
+            false => line.range.end,
+        }
+    }
+
+    fn index_at(&self, position: Vec2) -> usize {
+        let line_index = (position.y / self.line_height).floor().max(0.) as usize;
+        self.index_in_line(
+            line_index.min(self.lines.len().saturating_sub(1)),
+            position.x,
+        )
+    }
+
+    /// Byte index in the value of a display index
+    fn value_index(&self, index: usize) -> usize {
+        if self.placeholder {
+            0
+        } else if index <= self.composition.start {
+            index
+        } else if index >= self.composition.end {
+            index - self.composition.len()
+        } else {
+            self.composition.start
+        }
+    }
+
+    /// Rects covering the selection: the first line, the lines in between and the last line
+    fn selection_rects(&self, area: &TextArea, full_width: f32) -> [Rect; 3] {
+        let Some(selection) = area
+            .edit
+            .selection()
+            .filter(|_| self.composition.is_empty())
+        else {
+            return [Rect::default(); 3];
+        };
+
+        let start = self.position(selection.start);
+        let end = self.position(selection.end);
+        let line_height = self.line_height;
+        if start.y == end.y {
+            return [
+                Rect::new(start.x, start.y, end.x, end.y + line_height),
+                Rect::default(),
+                Rect::default(),
+            ];
+        }
+
+        let first_line = &self.lines[self.line_of(selection.start)];
+        [
+            Rect::new(
+                start.x,
+                start.y,
+                first_line.width().max(start.x + CARET_WIDTH),
+                start.y + line_height,
+            ),
+            Rect::new(0., start.y + line_height, full_width, end.y),
+            Rect::new(0., end.y, end.x, end.y + line_height),
+        ]
+    }
+}
+
+/// Breaks a line of the text at whitespace (or anywhere in long words) to fit `wrap_width`
+fn wrap_line(
+    lines: &mut Vec<TextAreaLine>,
+    text: &str,
+    start: usize,
+    wrap_width: Option<f32>,
+    font: Option<&Font>,
+    font_size: f32,
+) {
+    let offsets = char_offsets(text, font, font_size);
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let byte_index = |char_index: usize| {
+        chars
+            .get(char_index)
+            .map_or(text.len(), |(index, _)| *index)
+    };
+    let mut push_line = |from: usize, to: usize, soft_wrapped: bool| {
+        lines.push(TextAreaLine {
+            range: start + byte_index(from)..start + byte_index(to),
+            offsets: offsets[from..=to]
+                .iter()
+                .map(|offset| offset - offsets[from])
+                .collect(),
+            soft_wrapped,
+        });
+    };
+
+    let mut line_start = 0;
+    let mut last_break = None;
+    for (i, (_, c)) in chars.iter().enumerate() {
+        if let Some(wrap_width) = wrap_width {
+            // Whitespace may hang past the edge
+            if !c.is_whitespace()
+                && i > line_start
+                && offsets[i + 1] - offsets[line_start] > wrap_width
+            {
+                let at = last_break.filter(|at| *at > line_start).unwrap_or(i);
+                push_line(line_start, at, true);
+                line_start = at;
+                last_break = None;
+            }
+        }
+
+        if c.is_whitespace() {
+            last_break = Some(i + 1);
+        }
+    }
+
+    push_line(line_start, chars.len(), false);
+}
+
+/// Measures the areas with the font of their text
+#[derive(SystemParam)]
+struct TextAreaMetrics<'w, 's> {
+    q_node: Query<'w, 's, (Ref<'static, Node>, &'static GlobalTransform)>,
+    fonts: Res<'w, Assets<Font>>,
+    tokens: Res<'w, ThemeTokens>,
+}
+
+impl<'w, 's> TextAreaMetrics<'w, 's> {
+    fn layout(&self, area: &TextArea) -> TextAreaLayout {
+        let wrap_width = match area.config.wrap {
+            FlexWrap::NoWrap => None,
+            _ => self
+                .q_node
+                .get(area.lines_node)
+                .ok()
+                .map(|(node, _)| node.size().x)
+                .filter(|width| *width > 0.),
+        };
+
+        TextAreaLayout::new(
+            area,
+            wrap_width,
+            self.fonts.get(self.tokens.font.get(FontToken::Regular)),
+            self.tokens.font_size.get(FontSizeToken::Medium),
+        )
+    }
+
+    /// Space between the frame's border and the lines
+    fn padding(&self) -> f32 {
+        self.tokens.spacing.get(PADDING)
+    }
+
+    /// Number of lines scrolled by PageUp / PageDown
+    fn page_lines(&self, area: &TextArea, layout: &TextAreaLayout) -> usize {
+        let height = self
+            .q_node
+            .get(area.scroll_view)
+            .map_or(0., |(node, _)| node.size().y);
+
+        ((height - 2. * self.padding()) / layout.line_height)
+            .floor()
+            .max(1.) as usize
+    }
+}
+
+fn update_text_area_on_press(
+    mut q_areas: Query<(Entity, &mut TextArea, Ref<FluxInteraction>)>,
+    mut e_double_clicked: EventReader<DoubleClicked>,
+    metrics: TextAreaMetrics,
+    node_window: NodeWindow,
+    q_window: Query<&Window>,
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time<Real>>,
+) {
+    for event in e_double_clicked.read() {
+        if let Ok((_, mut area, _)) = q_areas.get_mut(event.entity) {
+            area.edit.select_word();
+        }
+    }
+
+    for (entity, mut area, flux_interaction) in &mut q_areas {
+        if *flux_interaction != FluxInteraction::Pressed {
+            continue;
+        }
+
+        let Some(position) = node_window
+            .window(entity)
+            .and_then(|window| q_window.get(window).ok())
+            .and_then(|window| window.cursor_position())
+            .and_then(|position| node_window.ui_position(entity, position))
+        else {
+            continue;
+        };
+        let Ok((lines_node, lines_transform)) = metrics.q_node.get(area.lines_node) else {
+            continue;
+        };
+
+        let layout = metrics.layout(&area);
+        let position = position - lines_node.logical_rect(lines_transform).min;
+        let index = layout.value_index(layout.index_at(position));
+
+        // Dragging while pressed extends the selection
+        if flux_interaction.is_changed() {
+            let extend = keys.symmetry_pressed(KeyCode::ShiftLeft);
+            area.edit.set_caret(index, extend);
+            area.preferred_x = None;
+            area.moved_caret(time.elapsed_seconds());
+        } else if area.edit.caret() != index {
+            area.edit.set_caret(index, true);
+            area.preferred_x = None;
+            area.moved_caret(time.elapsed_seconds());
+        }
+    }
+}
+
+fn update_text_area_on_keyboard(
+    focused: Res<FocusedEntity>,
+    mut q_areas: Query<&mut TextArea>,
+    metrics: TextAreaMetrics,
+    node_window: NodeWindow,
+    time: Res<Time<Real>>,
+    mut edit_events: TextEditEvents,
+    mut e_changed: EventWriter<TextAreaChanged>,
+) {
+    let modifiers = edit_events.modifiers();
+    let window = focused.0.and_then(|entity| node_window.window(entity));
+    let inputs = edit_events.read(window);
+
+    let Some(entity) = focused.0 else {
+        return;
+    };
+    let Ok(mut area) = q_areas.get_mut(entity) else {
+        return;
+    };
+    if inputs.is_empty() {
+        return;
+    }
+
+    let max_length = area.config.max_length;
+    let extend = modifiers.shift;
+    let mut changed = false;
+    for edit_input in inputs {
+        match edit_input {
+            TextEditInput::Key(event) => {
+                let layout = metrics.layout(&area);
+                let page = layout.lines.len().min(metrics.page_lines(&area, &layout)) as isize;
+                let line_move = match event.key_code {
+                    KeyCode::ArrowUp => Some(-1),
+                    KeyCode::ArrowDown => Some(1),
+                    KeyCode::PageUp => Some(-page),
+                    KeyCode::PageDown => Some(page),
+                    _ => None,
+                };
+
+                if let Some(line_move) = line_move {
+                    let line = layout.line_of(layout.caret) as isize + line_move;
+                    let x = area.preferred_x.unwrap_or(layout.position(layout.caret).x);
+                    let index = match line {
+                        line if line < 0 => 0,
+                        line if line >= layout.lines.len() as isize => layout.text.len(),
+                        line => layout.index_in_line(line as usize, x),
+                    };
+
+                    area.edit.set_caret(layout.value_index(index), extend);
+                    area.preferred_x = Some(x);
+                    continue;
+                }
+
+                area.preferred_x = None;
+                match event.key_code {
+                    KeyCode::Enter | KeyCode::NumpadEnter => {
+                        changed |= area.edit.insert("\n", max_length);
+                    }
+                    // Ctrl + Home / End go to the ends of the text
+                    KeyCode::Home if !modifiers.control => {
+                        let line = &layout.lines[layout.line_of(layout.caret)];
+                        area.edit
+                            .set_caret(layout.value_index(line.range.start), extend);
+                    }
+                    KeyCode::End if !modifiers.control => {
+                        let line_end = layout.line_end(layout.line_of(layout.caret));
+                        area.edit.set_caret(layout.value_index(line_end), extend);
+                    }
+                    _ => changed |= area.edit.handle_key(&event, modifiers, false).changed,
+                }
+            }
+            TextEditInput::Text(text) => {
+                area.preferred_x = None;
+                area.edit.set_composition(None);
+                changed |= area.edit.insert(&text, max_length);
+            }
+            TextEditInput::Preedit(value) => area.edit.set_composition(value.into()),
+        }
+    }
+
+    area.moved_caret(time.elapsed_seconds());
+
+    if changed {
+        e_changed.send(TextAreaChanged {
+            entity,
+            value: area.value().into(),
+        });
+    }
+}
+
+fn update_text_area_display(
+    mut q_areas: Query<(Entity, &mut TextArea)>,
+    mut q_text: Query<&mut Text>,
+    mut q_style: Query<&mut Style>,
+    mut q_scroll_view: Query<&mut ScrollView>,
+    metrics: TextAreaMetrics,
+    mut input_window: TextInputWindow,
+    focused: Res<FocusedEntity>,
+) {
+    let refresh = metrics.tokens.is_changed() || metrics.fonts.is_changed();
+
+    for (entity, mut area) in &mut q_areas {
+        let Ok((lines_node, lines_transform)) = metrics.q_node.get(area.lines_node) else {
+            continue;
+        };
+        if !refresh && !area.is_changed() && !lines_node.is_changed() {
+            continue;
+        }
+
+        let layout = metrics.layout(&area);
+        let padding = metrics.padding();
+        let no_wrap = area.config.wrap == FlexWrap::NoWrap;
+
+        if let Ok(mut text) = q_text.get_mut(area.text_node) {
+            let color = match layout.placeholder {
+                true => ColorToken::OutlineVariant,
+                false => ColorToken::OnInput,
+            };
+            let section = TextSection::new(
+                layout.wrapped_text(),
+                metrics.tokens.text_style(color, FontSizeToken::Medium),
+            );
+
+            if text.sections.len() != 1
+                || text.sections[0].value != section.value
+                || text.sections[0].style.color != section.style.color
+                || refresh
+            {
+                text.sections = vec![section];
+            }
+        }
+
+        // The frame fits the visible rows of the font
+        if let Ok(mut style) = q_style.get_mut(entity) {
+            let rows = area.config.rows.max(1) as f32;
+            let height = Val::Px(rows * layout.line_height + 2. * (padding + BORDER));
+            if style.height != height {
+                style.height = height;
+            }
+        }
+
+        if let Ok(mut style) = q_style.get_mut(area.lines_node) {
+            let height = Val::Px(layout.height());
+            let min_width = match no_wrap {
+                true => Val::Px(layout.width() + CARET_WIDTH),
+                false => Val::Auto,
+            };
+            if style.height != height || style.min_width != min_width {
+                style.height = height;
+                style.min_width = min_width;
+            }
+        }
+
+        let caret = layout.position(layout.caret);
+        if let Ok(mut style) = q_style.get_mut(area.caret_node) {
+            let (left, top, height) = (
+                Val::Px(caret.x),
+                Val::Px(caret.y),
+                Val::Px(layout.line_height),
+            );
+            if style.left != left || style.top != top || style.height != height {
+                style.left = left;
+                style.top = top;
+                style.height = height;
+            }
+        }
+
+        let rects = layout.selection_rects(&area, lines_node.size().x);
+        for (node, rect) in area.selection_nodes.iter().zip(rects) {
+            let Ok(mut style) = q_style.get_mut(*node) else {
+                continue;
+            };
+
+            let (left, top, width, height) = (
+                Val::Px(rect.min.x),
+                Val::Px(rect.min.y),
+                Val::Px(rect.width()),
+                Val::Px(rect.height()),
+            );
+            if style.left != left
+                || style.top != top
+                || style.width != width
+                || style.height != height
+            {
+                style.left = left;
+                style.top = top;
+                style.width = width;
+                style.height = height;
+            }
+        }
+
+        if area.scroll_to_caret {
+            area.bypass_change_detection().scroll_to_caret = false;
+
+            if let (Ok(mut scroll_view), Ok((view_node, _))) = (
+                q_scroll_view.get_mut(area.scroll_view),
+                metrics.q_node.get(area.scroll_view),
+            ) {
+                let view_size = view_node.size();
+                let caret_min = caret + Vec2::splat(padding);
+                let caret_max = caret_min + Vec2::new(CARET_WIDTH, layout.line_height);
+                let mut offset = scroll_view.scroll_offset();
+
+                if caret_min.y - padding < offset.y {
+                    offset.y = caret_min.y - padding;
+                } else if caret_max.y + padding > offset.y + view_size.y {
+                    offset.y = caret_max.y + padding - view_size.y;
+                }
+                if no_wrap {
+                    if caret_min.x - padding < offset.x {
+                        offset.x = caret_min.x - padding;
+                    } else if caret_max.x + padding > offset.x + view_size.x {
+                        offset.x = caret_max.x + padding - view_size.x;
+                    }
+                }
+
+                let offset = offset.max(Vec2::ZERO);
+                if offset != scroll_view.scroll_offset() {
+                    scroll_view.set_scroll_offset(offset);
+                }
+            }
+        }
+
+        // Candidate box of the IME below the caret
+        if focused.0 == Some(entity) {
+            if let Some(mut window) = input_window.get_mut(entity) {
+                let origin = lines_node.logical_rect(lines_transform).min;
+                window.ime_position = origin + caret + Vec2::new(0., layout.line_height);
+            }
+        }
+    }
+}
+
+pub trait UiTextAreaExt<'w, 's> {
+    fn text_area<'a>(&'a mut self, config: TextAreaConfig) -> UiBuilder<'w, 's, 'a, Entity>;
+}
+
+impl<'w, 's> UiTextAreaExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
+    fn text_area<'a>(&'a mut self, config: TextAreaConfig) -> UiBuilder<'w, 's, 'a, Entity> {
+        let mut scroll_view = Entity::PLACEHOLDER;
+        let mut lines_node = Entity::PLACEHOLDER;
+        let mut text_node = Entity::PLACEHOLDER;
+        let mut caret_node = Entity::PLACEHOLDER;
+        let mut selection_nodes = [Entity::PLACEHOLDER; 3];
+        let restrict_to = match config.wrap {
+            FlexWrap::NoWrap => None,
+            _ => Some(ScrollAxis::Vertical),
+        };
+
+        let mut area = self.container(TextArea::frame(), |frame| {
+            scroll_view = frame
+                .scroll_view(restrict_to, |content| {
+                    lines_node = content
+                        .container(TextArea::lines_bundle(), |lines| {
+                            for node in selection_nodes.iter_mut() {
+                                *node = lines.spawn(TextArea::selection_bundle()).id();
+                            }
+                            text_node = lines.spawn(TextArea::text_bundle()).id();
+                            caret_node = lines.spawn(TextArea::caret_bundle()).id();
+                        })
+                        .id();
+                })
+                .id();
+        });
+
+        let mut text_area = TextArea {
+            config,
+            scroll_view,
+            lines_node,
+            text_node,
+            caret_node,
+            selection_nodes,
+            ..default()
+        };
+        text_area.set_value(text_area.config.value.clone());
+        area.insert(text_area);
+
+        area
+    }
+}
@@ -0,0 +1,1278 @@
+use std::ops::Range;
+
+use ab_glyph::{Font as _, ScaleFont};
+use bevy::{
+    ecs::system::SystemParam,
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState,
+    },
+    prelude::*,
+    ui::FocusPolicy,
+    window::{Ime, ReceivedCharacter},
+};
+
+use crate::{
+    focus_interaction::{CaptureKeyboardInput, Focusable, FocusedEntity},
+    input_extension::SymmetricKeysExt,
+    interactions::PseudoStates,
+    node_window::NodeWindow,
+    theme::{
+        tokens::{
+            ColorToken, FontSizeToken, FontToken, SpacingToken, ThemeTokens, TokenRect, TokenStyle,
+        },
+        ComponentThemePlugin,
+    },
+    ui_builder::UiBuilder,
+    ui_callbacks::{
+        report_ui_callbacks, UiCallbackEvent, UiCallbackTrigger, UiCallbackTriggered,
+        UiCallbackUpdate,
+    },
+    DoubleClicked, FluxInteraction, FluxInteractionUpdate, TrackedInteraction,
+};
+
+use super::prelude::UiContainerExt;
+
+pub(crate) const PADDING: SpacingToken = SpacingToken::Small;
+const VERTICAL_PADDING: SpacingToken = SpacingToken::ExtraSmall;
+pub(crate) const BORDER: f32 = 1.;
+pub(crate) const CARET_WIDTH: f32 = 1.;
+const CARET_BLINK_INTERVAL: f32 = 0.5;
+const HISTORY_LIMIT: usize = 100;
+const PASSWORD_MASK: char = '•';
+
+pub struct TextInputPlugin;
+
+impl Plugin for TextInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(ComponentThemePlugin::<TextInput>::default())
+            .register_type::<TextInput>()
+            .add_event::<TextInputChanged>()
+            .add_event::<UiCallbackTriggered>()
+            .add_systems(
+                Update,
+                report_ui_callbacks::<TextInputChanged>
+                    .after(TextInputUpdate)
+                    .before(UiCallbackUpdate),
+            )
+            .add_event::<TextInputSubmitted>()
+            .configure_sets(Update, TextInputUpdate.after(FluxInteractionUpdate))
+            .add_systems(
+                Update,
+                (
+                    update_text_edit_focus::<TextInput>,
+                    update_text_input_on_press,
+                    update_text_input_on_keyboard,
+                    update_text_input_display,
+                    update_text_edit_caret::<TextInput>,
+                )
+                    .chain()
+                    .in_set(TextInputUpdate),
+            );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct TextInputUpdate;
+
+/// Sent when the value is edited by the user
+#[derive(Event, Clone, Debug)]
+pub struct TextInputChanged {
+    pub entity: Entity,
+    pub value: String,
+}
+
+impl UiCallbackEvent for TextInputChanged {
+    const TRIGGER: UiCallbackTrigger = UiCallbackTrigger::Change;
+
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+/// Sent when Enter is pressed in the input
+#[derive(Event, Clone, Debug)]
+pub struct TextInputSubmitted {
+    pub entity: Entity,
+    pub value: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TextEditKind {
+    Insert,
+    Delete,
+}
+
+#[derive(Clone, Debug)]
+struct TextEditSnapshot {
+    value: String,
+    caret: usize,
+    anchor: usize,
+}
+
+#[derive(Clone, Debug, Default)]
+struct TextEditHistory {
+    undo: Vec<TextEditSnapshot>,
+    redo: Vec<TextEditSnapshot>,
+    /// Consecutive edits of the same kind are undone together
+    last_edit: Option<TextEditKind>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CharClass {
+    Whitespace,
+    Word,
+    Punctuation,
+}
+
+impl CharClass {
+    fn of(c: char) -> Self {
+        if c.is_whitespace() {
+            CharClass::Whitespace
+        } else if c.is_alphanumeric() || c == '_' {
+            CharClass::Word
+        } else {
+            CharClass::Punctuation
+        }
+    }
+}
+
+/// Value, caret and undo history of an editable text.
+///
+/// Indices are byte indices into the value, always on a char boundary.
+/// The selection spans between the anchor and the caret.
+#[derive(Clone, Debug, Default, Reflect)]
+pub struct TextEdit {
+    value: String,
+    caret: usize,
+    anchor: usize,
+    /// Text being composed by the IME, displayed at the caret
+    composition: Option<String>,
+    #[reflect(ignore)]
+    history: TextEditHistory,
+}
+
+impl TextEdit {
+    pub fn new(value: impl Into<String>) -> Self {
+        let value: String = value.into();
+        let caret = value.len();
+
+        Self {
+            value,
+            caret,
+            anchor: caret,
+            ..default()
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    pub fn selection(&self) -> Option<Range<usize>> {
+        match self.caret == self.anchor {
+            true => None,
+            false => Some(self.caret.min(self.anchor)..self.caret.max(self.anchor)),
+        }
+    }
+
+    pub fn composition(&self) -> Option<&str> {
+        self.composition.as_deref()
+    }
+
+    /// Zero-based line and column (in chars) of the caret
+    pub fn line_column(&self) -> (usize, usize) {
+        let before = &self.value[..self.caret];
+        let line = before.matches('\n').count();
+        let column = before
+            .rsplit('\n')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .count();
+
+        (line, column)
+    }
+
+    /// Replaces the value, moving the caret to its end and clearing the undo history
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        *self = Self::new(value);
+    }
+
+    pub(crate) fn set_caret(&mut self, index: usize, extend: bool) {
+        let mut index = index.min(self.value.len());
+        while !self.value.is_char_boundary(index) {
+            index -= 1;
+        }
+
+        self.caret = index;
+        if !extend {
+            self.anchor = index;
+        }
+        self.history.last_edit = None;
+    }
+
+    pub(crate) fn set_composition(&mut self, composition: Option<String>) {
+        self.composition = composition.filter(|composition| !composition.is_empty());
+    }
+
+    pub(crate) fn move_left(&mut self, word: bool, extend: bool) {
+        match (self.selection(), extend) {
+            (Some(selection), false) => self.set_caret(selection.start, false),
+            _ => {
+                let index = match word {
+                    true => self.prev_word_boundary(self.caret),
+                    false => self.prev_boundary(self.caret),
+                };
+                self.set_caret(index, extend);
+            }
+        }
+    }
+
+    pub(crate) fn move_right(&mut self, word: bool, extend: bool) {
+        match (self.selection(), extend) {
+            (Some(selection), false) => self.set_caret(selection.end, false),
+            _ => {
+                let index = match word {
+                    true => self.next_word_boundary(self.caret),
+                    false => self.next_boundary(self.caret),
+                };
+                self.set_caret(index, extend);
+            }
+        }
+    }
+
+    pub(crate) fn select_all(&mut self) {
+        self.anchor = 0;
+        self.caret = self.value.len();
+        self.history.last_edit = None;
+    }
+
+    /// Selects the word, whitespace or punctuation run at the caret
+    pub(crate) fn select_word(&mut self) {
+        let class = self.value[self.caret..]
+            .chars()
+            .next()
+            .or_else(|| self.value[..self.caret].chars().next_back())
+            .map(CharClass::of);
+        let Some(class) = class else {
+            return;
+        };
+
+        let start = self.value[..self.caret]
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| CharClass::of(*c) == class)
+            .last()
+            .map_or(self.caret, |(index, _)| index);
+        let end = self.value[self.caret..]
+            .char_indices()
+            .take_while(|(_, c)| CharClass::of(*c) == class)
+            .last()
+            .map_or(self.caret, |(index, c)| self.caret + index + c.len_utf8());
+
+        self.anchor = start;
+        self.caret = end;
+        self.history.last_edit = None;
+    }
+
+    /// Replaces the selection with `text`, cut to fit `max_length` chars.
+    /// Returns whether the value changed.
+    pub(crate) fn insert(&mut self, text: &str, max_length: Option<usize>) -> bool {
+        let selection = self.selection().unwrap_or(self.caret..self.caret);
+        let text: String = match max_length {
+            Some(max_length) => {
+                let kept =
+                    self.value.chars().count() - self.value[selection.clone()].chars().count();
+                text.chars().take(max_length.saturating_sub(kept)).collect()
+            }
+            None => text.into(),
+        };
+
+        if text.is_empty() && selection.is_empty() {
+            return false;
+        }
+
+        self.record(TextEditKind::Insert);
+        self.value.replace_range(selection.clone(), &text);
+        self.caret = selection.start + text.len();
+        self.anchor = self.caret;
+
+        true
+    }
+
+    /// Deletes the selection or the char (or word) before the caret
+    pub(crate) fn delete_backward(&mut self, word: bool) -> bool {
+        let range = match self.selection() {
+            Some(selection) => selection,
+            None => match word {
+                true => self.prev_word_boundary(self.caret)..self.caret,
+                false => self.prev_boundary(self.caret)..self.caret,
+            },
+        };
+
+        self.delete(range)
+    }
+
+    /// Deletes the selection or the char (or word) after the caret
+    pub(crate) fn delete_forward(&mut self, word: bool) -> bool {
+        let range = match self.selection() {
+            Some(selection) => selection,
+            None => match word {
+                true => self.caret..self.next_word_boundary(self.caret),
+                false => self.caret..self.next_boundary(self.caret),
+            },
+        };
+
+        self.delete(range)
+    }
+
+    pub(crate) fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.history.undo.pop() else {
+            return false;
+        };
+
+        let current = self.snapshot();
+        self.history.redo.push(current);
+        self.restore(snapshot);
+
+        true
+    }
+
+    pub(crate) fn redo(&mut self) -> bool {
+        let Some(snapshot) = self.history.redo.pop() else {
+            return false;
+        };
+
+        let current = self.snapshot();
+        self.history.undo.push(current);
+        self.restore(snapshot);
+
+        true
+    }
+
+    pub(crate) fn prev_boundary(&self, index: usize) -> usize {
+        self.value[..index]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(index, _)| index)
+    }
+
+    pub(crate) fn next_boundary(&self, index: usize) -> usize {
+        self.value[index..]
+            .chars()
+            .next()
+            .map_or(index, |c| index + c.len_utf8())
+    }
+
+    /// Start of the word before `index`, skipping whitespace
+    pub(crate) fn prev_word_boundary(&self, index: usize) -> usize {
+        let mut chars = self.value[..index].char_indices().rev().peekable();
+        let mut boundary = index;
+
+        while let Some((i, _)) = chars.next_if(|(_, c)| c.is_whitespace()) {
+            boundary = i;
+        }
+
+        let Some(class) = chars.peek().map(|(_, c)| CharClass::of(*c)) else {
+            return boundary;
+        };
+
+        while let Some((i, _)) = chars.next_if(|(_, c)| CharClass::of(*c) == class) {
+            boundary = i;
+        }
+
+        boundary
+    }
+
+    /// End of the word after `index`, including the whitespace following it
+    pub(crate) fn next_word_boundary(&self, index: usize) -> usize {
+        let mut chars = self.value[index..]
+            .char_indices()
+            .map(|(i, c)| (index + i + c.len_utf8(), c))
+            .peekable();
+        let mut boundary = index;
+
+        if let Some(class) = chars.peek().map(|(_, c)| CharClass::of(*c)) {
+            if class != CharClass::Whitespace {
+                while let Some((i, _)) = chars.next_if(|(_, c)| CharClass::of(*c) == class) {
+                    boundary = i;
+                }
+            }
+        }
+
+        while let Some((i, _)) = chars.next_if(|(_, c)| c.is_whitespace()) {
+            boundary = i;
+        }
+
+        boundary
+    }
+
+    fn delete(&mut self, range: Range<usize>) -> bool {
+        if range.is_empty() {
+            return false;
+        }
+
+        self.record(TextEditKind::Delete);
+        self.value.replace_range(range.clone(), "");
+        self.caret = range.start;
+        self.anchor = range.start;
+
+        true
+    }
+
+    fn record(&mut self, kind: TextEditKind) {
+        if self.history.last_edit != Some(kind) {
+            let snapshot = self.snapshot();
+            self.history.undo.push(snapshot);
+            if self.history.undo.len() > HISTORY_LIMIT {
+                self.history.undo.remove(0);
+            }
+        }
+
+        self.history.redo.clear();
+        self.history.last_edit = kind.into();
+    }
+
+    fn snapshot(&self) -> TextEditSnapshot {
+        TextEditSnapshot {
+            value: self.value.clone(),
+            caret: self.caret,
+            anchor: self.anchor,
+        }
+    }
+
+    fn restore(&mut self, snapshot: TextEditSnapshot) {
+        self.value = snapshot.value;
+        self.caret = snapshot.caret;
+        self.anchor = snapshot.anchor;
+        self.composition = None;
+        self.history.last_edit = None;
+    }
+}
+
+/// Modifier keys held while editing
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct TextEditModifiers {
+    pub control: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+impl TextEditModifiers {
+    pub(crate) fn from_keys(keys: &ButtonInput<KeyCode>) -> Self {
+        Self {
+            control: keys.symmetry_pressed(KeyCode::ControlLeft),
+            shift: keys.symmetry_pressed(KeyCode::ShiftLeft),
+            alt: keys.symmetry_pressed(KeyCode::AltLeft),
+        }
+    }
+
+    /// Ctrl without Alt (AltGr) is a shortcut, not a character
+    pub(crate) fn accepts_characters(&self) -> bool {
+        !self.control || self.alt
+    }
+}
+
+/// Result of a key press in a [`TextEdit`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct TextEditResponse {
+    pub handled: bool,
+    pub changed: bool,
+}
+
+impl TextEdit {
+    /// Applies the editing and caret keys shared by the text widgets.
+    ///
+    /// Word-wise navigation and deletion jumps to the ends of `masked` values.
+    pub(crate) fn handle_key(
+        &mut self,
+        event: &KeyboardInput,
+        modifiers: TextEditModifiers,
+        masked: bool,
+    ) -> TextEditResponse {
+        let word = modifiers.control;
+        let extend = modifiers.shift;
+        let mut response = TextEditResponse {
+            handled: true,
+            changed: false,
+        };
+
+        match event.key_code {
+            KeyCode::ArrowLeft if word && masked => self.set_caret(0, extend),
+            KeyCode::ArrowLeft => self.move_left(word, extend),
+            KeyCode::ArrowRight if word && masked => self.set_caret(self.value.len(), extend),
+            KeyCode::ArrowRight => self.move_right(word, extend),
+            KeyCode::Home => self.set_caret(0, extend),
+            KeyCode::End => self.set_caret(self.value.len(), extend),
+            KeyCode::Backspace if word && masked && self.selection().is_none() => {
+                response.changed = self.delete(0..self.caret);
+            }
+            KeyCode::Backspace => response.changed = self.delete_backward(word),
+            KeyCode::Delete if word && masked && self.selection().is_none() => {
+                response.changed = self.delete(self.caret..self.value.len());
+            }
+            KeyCode::Delete => response.changed = self.delete_forward(word),
+            _ => {
+                let Key::Character(character) = &event.logical_key else {
+                    return TextEditResponse::default();
+                };
+                if !modifiers.control {
+                    return TextEditResponse::default();
+                }
+
+                match character.to_lowercase().as_str() {
+                    "a" => self.select_all(),
+                    "z" if modifiers.shift => response.changed = self.redo(),
+                    "z" => response.changed = self.undo(),
+                    "y" => response.changed = self.redo(),
+                    _ => return TextEditResponse::default(),
+                }
+            }
+        }
+
+        response
+    }
+}
+
+#[derive(Clone, Debug, Default, Reflect)]
+pub struct TextInputConfig {
+    pub value: String,
+    /// Shown while the value is empty
+    pub placeholder: String,
+    /// Maximum number of characters
+    pub max_length: Option<usize>,
+    /// Displays every character as a bullet
+    pub password: bool,
+}
+
+impl TextInputConfig {
+    pub fn from(value: impl Into<String>) -> TextInputConfig {
+        TextInputConfig {
+            value: value.into(),
+            ..default()
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct TextInput {
+    pub config: TextInputConfig,
+    edit: TextEdit,
+    scroll: f32,
+    caret_moved_at: f32,
+    text_node: Entity,
+    caret_node: Entity,
+    selection_node: Entity,
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        Self {
+            config: Default::default(),
+            edit: Default::default(),
+            scroll: 0.,
+            caret_moved_at: 0.,
+            text_node: Entity::PLACEHOLDER,
+            caret_node: Entity::PLACEHOLDER,
+            selection_node: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl TextInput {
+    pub fn value(&self) -> &str {
+        self.edit.value()
+    }
+
+    /// Byte range of the selected text, if any
+    pub fn selection(&self) -> Option<Range<usize>> {
+        self.edit.selection()
+    }
+
+    pub fn select_all(&mut self) {
+        self.edit.select_all();
+    }
+
+    /// Replaces the value, cut to the max length. Does not send [`TextInputChanged`].
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        let value: String = value.into();
+        let value = match self.config.max_length {
+            Some(max_length) => value.chars().take(max_length).collect(),
+            None => value,
+        };
+
+        self.edit.set_value(value);
+    }
+
+    fn frame() -> impl Bundle {
+        (
+            Name::new("Text Input"),
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    border: UiRect::all(Val::Px(BORDER)),
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                focus_policy: FocusPolicy::Block,
+                ..default()
+            },
+            Interaction::default(),
+            TrackedInteraction::default(),
+            Focusable::default(),
+            CaptureKeyboardInput,
+            PseudoStates::default(),
+            TokenStyle {
+                background: ColorToken::Input.into(),
+                border: ColorToken::Outline.into(),
+                margin: TokenRect::all(SpacingToken::Small).into(),
+                ..default()
+            },
+        )
+    }
+
+    fn selection_bundle() -> impl Bundle {
+        (
+            Name::new("Selection"),
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            TokenStyle {
+                background: ColorToken::Highlight.into(),
+                ..default()
+            },
+        )
+    }
+
+    fn text_bundle() -> impl Bundle {
+        (
+            Name::new("Text"),
+            TextBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                text: Text::default().with_no_wrap(),
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+        )
+    }
+
+    fn caret_bundle() -> impl Bundle {
+        (
+            Name::new("Caret"),
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Px(CARET_WIDTH),
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            TokenStyle {
+                background: ColorToken::OnInput.into(),
+                ..default()
+            },
+        )
+    }
+}
+
+/// Widgets editing a [`TextEdit`], sharing focus, IME and caret handling
+pub(crate) trait TextEditWidget: Component {
+    fn edit(&self) -> &TextEdit;
+    fn edit_mut(&mut self) -> &mut TextEdit;
+    /// The caret is shown for a blink interval after it moved
+    fn caret_moved_at(&self) -> f32;
+    fn set_caret_moved_at(&mut self, now: f32);
+    fn caret_node(&self) -> Entity;
+    fn selection_nodes(&self) -> &[Entity];
+}
+
+impl TextEditWidget for TextInput {
+    fn edit(&self) -> &TextEdit {
+        &self.edit
+    }
+
+    fn edit_mut(&mut self) -> &mut TextEdit {
+        &mut self.edit
+    }
+
+    fn caret_moved_at(&self) -> f32 {
+        self.caret_moved_at
+    }
+
+    fn set_caret_moved_at(&mut self, now: f32) {
+        self.caret_moved_at = now;
+    }
+
+    fn caret_node(&self) -> Entity {
+        self.caret_node
+    }
+
+    fn selection_nodes(&self) -> &[Entity] {
+        std::slice::from_ref(&self.selection_node)
+    }
+}
+
+/// X offset of every char boundary of `text`, measured with the font's advances
+pub(crate) fn char_offsets(text: &str, font: Option<&Font>, font_size: f32) -> Vec<f32> {
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    let mut x = 0.;
+    offsets.push(x);
+
+    match font {
+        Some(font) => {
+            let font = font.font.as_scaled(font_size);
+            let mut previous = None;
+            for c in text.chars() {
+                let glyph = font.glyph_id(c);
+                if let Some(previous) = previous {
+                    x += font.kern(previous, glyph);
+                }
+                x += font.h_advance(glyph);
+                offsets.push(x);
+                previous = glyph.into();
+            }
+        }
+        // Font still loading, good enough until it is
+        None => {
+            for _ in text.chars() {
+                x += font_size * 0.5;
+                offsets.push(x);
+            }
+        }
+    }
+
+    offsets
+}
+
+/// Height of a line of text in the font, including the line gap
+pub(crate) fn line_height(font: Option<&Font>, font_size: f32) -> f32 {
+    match font {
+        Some(font) => {
+            let font = font.font.as_scaled(font_size);
+            font.height() + font.line_gap()
+        }
+        None => font_size * 1.2,
+    }
+}
+
+/// The displayed text of an input and where its chars are
+struct TextInputLayout {
+    text: String,
+    placeholder: bool,
+    offsets: Vec<f32>,
+    line_height: f32,
+    /// Space between the frame's border and the text
+    padding: Vec2,
+    /// Display char index of the caret
+    caret: usize,
+    /// Display char range of the selection
+    selection: Option<Range<usize>>,
+}
+
+impl TextInputLayout {
+    fn new(input: &TextInput, fonts: &Assets<Font>, tokens: &ThemeTokens) -> Self {
+        let edit = &input.edit;
+        let char_index = |index: usize| edit.value[..index].chars().count();
+        let mask = |text: &str| match input.config.password {
+            true => text.chars().map(|_| PASSWORD_MASK).collect(),
+            false => text.to_string(),
+        };
+
+        let composition = edit.composition().unwrap_or_default();
+        let composition_length = composition.chars().count();
+        let placeholder = edit.value.is_empty() && composition.is_empty();
+        let text = match placeholder {
+            true => input.config.placeholder.clone(),
+            false => {
+                let (before, after) = edit.value.split_at(edit.caret);
+                format!("{}{}{}", mask(before), composition, mask(after))
+            }
+        };
+
+        let font = fonts.get(tokens.font.get(FontToken::Regular));
+        let font_size = tokens.font_size.get(FontSizeToken::Medium);
+        let offsets = match placeholder {
+            true => vec![0.],
+            false => char_offsets(&text, font, font_size),
+        };
+
+        Self {
+            text,
+            placeholder,
+            offsets,
+            line_height: line_height(font, font_size),
+            padding: Vec2::new(
+                tokens.spacing.get(PADDING),
+                tokens.spacing.get(VERTICAL_PADDING),
+            ),
+            caret: char_index(edit.caret) + composition_length,
+            selection: match composition.is_empty() {
+                true => edit
+                    .selection()
+                    .map(|selection| char_index(selection.start)..char_index(selection.end)),
+                false => None,
+            },
+        }
+    }
+
+    fn x(&self, char_index: usize) -> f32 {
+        self.offsets
+            .get(char_index)
+            .or(self.offsets.last())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn width(&self) -> f32 {
+        self.offsets.last().copied().unwrap_or_default()
+    }
+
+    /// Byte index in the value of the char boundary nearest to `x`
+    fn value_index_at(&self, edit: &TextEdit, x: f32) -> usize {
+        if self.placeholder {
+            return 0;
+        }
+
+        let char_index = self
+            .offsets
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - x).abs().total_cmp(&(*b - x).abs()))
+            .map_or(0, |(index, _)| index);
+
+        // Skip over the composition, it is not part of the value yet
+        let caret = edit.value[..edit.caret].chars().count();
+        let composition_length = edit.composition().map_or(0, |c| c.chars().count());
+        let char_index = match char_index > caret {
+            true => caret.max(char_index.saturating_sub(composition_length)),
+            false => char_index,
+        };
+
+        edit.value
+            .char_indices()
+            .nth(char_index)
+            .map_or(edit.value.len(), |(index, _)| index)
+    }
+}
+
+/// The window of an input, for IME settings
+#[derive(SystemParam)]
+pub(crate) struct TextInputWindow<'w, 's> {
+    node_window: NodeWindow<'w, 's>,
+    q_window: Query<'w, 's, &'static mut Window>,
+}
+
+impl<'w, 's> TextInputWindow<'w, 's> {
+    pub(crate) fn get_mut(&mut self, entity: Entity) -> Option<Mut<'_, Window>> {
+        let window = self.node_window.window(entity)?;
+        self.q_window.get_mut(window).ok()
+    }
+}
+
+pub(crate) fn update_text_edit_focus<C: TextEditWidget>(
+    focused: Res<FocusedEntity>,
+    mut q_widgets: Query<&mut C>,
+    mut input_window: TextInputWindow,
+    time: Res<Time<Real>>,
+    mut prev_focused: Local<Option<Entity>>,
+) {
+    if *prev_focused == focused.0 {
+        return;
+    }
+
+    if let Some(prev) = prev_focused.take() {
+        if let Ok(mut widget) = q_widgets.get_mut(prev) {
+            widget.edit_mut().set_composition(None);
+
+            if let Some(mut window) = input_window.get_mut(prev) {
+                window.ime_enabled = false;
+            }
+        }
+    }
+
+    if let Some(entity) = focused.0 {
+        if let Ok(mut widget) = q_widgets.get_mut(entity) {
+            widget.set_caret_moved_at(time.elapsed_seconds());
+
+            if let Some(mut window) = input_window.get_mut(entity) {
+                window.ime_enabled = true;
+            }
+        }
+    }
+
+    *prev_focused = focused.0;
+}
+
+#[derive(SystemParam)]
+struct TextInputPointer<'w, 's> {
+    node_window: NodeWindow<'w, 's>,
+    q_window: Query<'w, 's, &'static Window>,
+    fonts: Res<'w, Assets<Font>>,
+    tokens: Res<'w, ThemeTokens>,
+}
+
+impl<'w, 's> TextInputPointer<'w, 's> {
+    /// Cursor position in UI coordinates of the node's camera
+    fn cursor_position(&self, entity: Entity) -> Option<Vec2> {
+        let window = self.q_window.get(self.node_window.window(entity)?).ok()?;
+        self.node_window
+            .ui_position(entity, window.cursor_position()?)
+    }
+}
+
+fn update_text_input_on_press(
+    mut q_inputs: Query<(
+        Entity,
+        &mut TextInput,
+        Ref<FluxInteraction>,
+        &Node,
+        &GlobalTransform,
+    )>,
+    mut e_double_clicked: EventReader<DoubleClicked>,
+    pointer: TextInputPointer,
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time<Real>>,
+) {
+    for event in e_double_clicked.read() {
+        if let Ok((_, mut input, _, _, _)) = q_inputs.get_mut(event.entity) {
+            match input.config.password {
+                true => input.edit.select_all(),
+                false => input.edit.select_word(),
+            }
+        }
+    }
+
+    for (entity, mut input, flux_interaction, node, transform) in &mut q_inputs {
+        if *flux_interaction != FluxInteraction::Pressed {
+            continue;
+        }
+
+        let Some(position) = pointer.cursor_position(entity) else {
+            continue;
+        };
+
+        let layout = TextInputLayout::new(&input, &pointer.fonts, &pointer.tokens);
+        let x = position.x - node.logical_rect(transform).min.x - layout.padding.x + input.scroll;
+        let index = layout.value_index_at(&input.edit, x);
+
+        // Dragging while pressed extends the selection
+        if flux_interaction.is_changed() {
+            let extend = keys.symmetry_pressed(KeyCode::ShiftLeft);
+            input.edit.set_caret(index, extend);
+            input.caret_moved_at = time.elapsed_seconds();
+        } else if input.edit.caret() != index {
+            input.edit.set_caret(index, true);
+            input.caret_moved_at = time.elapsed_seconds();
+        }
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct TextEditEvents<'w, 's> {
+    keyboard: EventReader<'w, 's, KeyboardInput>,
+    characters: EventReader<'w, 's, ReceivedCharacter>,
+    ime: EventReader<'w, 's, Ime>,
+    keys: Res<'w, ButtonInput<KeyCode>>,
+}
+
+/// Input of a frame for the focused text widget
+pub(crate) enum TextEditInput {
+    Key(KeyboardInput),
+    Text(String),
+    Preedit(String),
+}
+
+impl<'w, 's> TextEditEvents<'w, 's> {
+    pub(crate) fn modifiers(&self) -> TextEditModifiers {
+        TextEditModifiers::from_keys(&self.keys)
+    }
+
+    /// Reads all events, keeping those sent to `window`. Characters are dropped with a shortcut held.
+    pub(crate) fn read(&mut self, window: Option<Entity>) -> Vec<TextEditInput> {
+        let modifiers = self.modifiers();
+        let in_window = |event_window: Entity| window.is_none() || window == Some(event_window);
+        let mut inputs: Vec<TextEditInput> = self
+            .keyboard
+            .read()
+            .filter(|event| event.state == ButtonState::Pressed && in_window(event.window))
+            .map(|event| TextEditInput::Key(event.clone()))
+            .collect();
+
+        let characters: String = self
+            .characters
+            .read()
+            .filter(|event| in_window(event.window))
+            .flat_map(|event| event.char.chars())
+            .filter(|c| !c.is_control())
+            .collect();
+        if !characters.is_empty() && modifiers.accepts_characters() {
+            inputs.push(TextEditInput::Text(characters));
+        }
+
+        for event in self.ime.read() {
+            match event {
+                Ime::Preedit { window, value, .. } if in_window(*window) => {
+                    inputs.push(TextEditInput::Preedit(value.clone()));
+                }
+                Ime::Commit { window, value } if in_window(*window) => {
+                    inputs.push(TextEditInput::Text(value.clone()));
+                }
+                _ => (),
+            }
+        }
+
+        inputs
+    }
+}
+
+#[derive(SystemParam)]
+struct TextInputEvents<'w> {
+    changed: EventWriter<'w, TextInputChanged>,
+    submitted: EventWriter<'w, TextInputSubmitted>,
+}
+
+fn update_text_input_on_keyboard(
+    focused: Res<FocusedEntity>,
+    mut q_inputs: Query<&mut TextInput>,
+    node_window: NodeWindow,
+    time: Res<Time<Real>>,
+    mut edit_events: TextEditEvents,
+    mut events: TextInputEvents,
+) {
+    let modifiers = edit_events.modifiers();
+    let window = focused.0.and_then(|entity| node_window.window(entity));
+    let inputs = edit_events.read(window);
+
+    let Some(entity) = focused.0 else {
+        return;
+    };
+    let Ok(mut input) = q_inputs.get_mut(entity) else {
+        return;
+    };
+    if inputs.is_empty() {
+        return;
+    }
+
+    let max_length = input.config.max_length;
+    let masked = input.config.password;
+    let mut changed = false;
+    let mut submitted = false;
+    for edit_input in inputs {
+        match edit_input {
+            TextEditInput::Key(event) => match event.key_code {
+                KeyCode::Enter | KeyCode::NumpadEnter => submitted = true,
+                _ => changed |= input.edit.handle_key(&event, modifiers, masked).changed,
+            },
+            TextEditInput::Text(text) => {
+                input.edit.set_composition(None);
+                changed |= input.edit.insert(&text, max_length);
+            }
+            TextEditInput::Preedit(value) => input.edit.set_composition(value.into()),
+        }
+    }
+
+    input.caret_moved_at = time.elapsed_seconds();
+
+    if changed {
+        events.changed.send(TextInputChanged {
+            entity,
+            value: input.value().into(),
+        });
+    }
+
+    if submitted {
+        events.submitted.send(TextInputSubmitted {
+            entity,
+            value: input.value().into(),
+        });
+    }
+}
+
+fn update_text_input_display(
+    mut q_inputs: Query<(Entity, &mut TextInput, Ref<Node>, &GlobalTransform)>,
+    mut q_text: Query<&mut Text>,
+    mut q_style: Query<&mut Style>,
+    mut input_window: TextInputWindow,
+    focused: Res<FocusedEntity>,
+    fonts: Res<Assets<Font>>,
+    tokens: Res<ThemeTokens>,
+) {
+    let refresh = tokens.is_changed() || fonts.is_changed();
+
+    for (entity, mut input, node, transform) in &mut q_inputs {
+        if !refresh && !input.is_changed() && !node.is_changed() {
+            continue;
+        }
+
+        let layout = TextInputLayout::new(&input, &fonts, &tokens);
+
+        // Keep the caret in view
+        let caret_x = layout.x(layout.caret);
+        let visible_width = (node.size().x - 2. * layout.padding.x).max(0.);
+        let max_scroll = (layout.width() + CARET_WIDTH - visible_width).max(0.);
+        let mut scroll = input.scroll.clamp(0., max_scroll);
+        if caret_x + CARET_WIDTH - scroll > visible_width {
+            scroll = caret_x + CARET_WIDTH - visible_width;
+        } else if caret_x < scroll {
+            scroll = caret_x;
+        }
+        if scroll != input.scroll {
+            input.bypass_change_detection().scroll = scroll;
+        }
+
+        if let Ok(mut text) = q_text.get_mut(input.text_node) {
+            let color = match layout.placeholder {
+                true => ColorToken::OutlineVariant,
+                false => ColorToken::OnInput,
+            };
+            let section = TextSection::new(
+                layout.text.clone(),
+                tokens.text_style(color, FontSizeToken::Medium),
+            );
+
+            if text.sections.len() != 1
+                || text.sections[0].value != section.value
+                || text.sections[0].style.color != section.style.color
+                || refresh
+            {
+                text.sections = vec![section];
+            }
+        }
+
+        // The frame fits a line of the font
+        let height = Val::Px(layout.line_height);
+        let frame_height = Val::Px(layout.line_height + 2. * (layout.padding.y + BORDER));
+        for (node, height) in [
+            (entity, frame_height),
+            (input.caret_node, height),
+            (input.selection_node, height),
+        ] {
+            if let Ok(mut style) = q_style.get_mut(node) {
+                if style.height != height {
+                    style.height = height;
+                }
+            }
+        }
+
+        let left = layout.padding.x - scroll;
+        let top = Val::Px(layout.padding.y);
+        if let Ok(mut style) = q_style.get_mut(input.text_node) {
+            if style.left != Val::Px(left) || style.top != top {
+                style.left = Val::Px(left);
+                style.top = top;
+            }
+        }
+
+        if let Ok(mut style) = q_style.get_mut(input.caret_node) {
+            if style.left != Val::Px(left + caret_x) || style.top != top {
+                style.left = Val::Px(left + caret_x);
+                style.top = top;
+            }
+        }
+
+        if let (Some(selection), Ok(mut style)) = (
+            layout.selection.clone(),
+            q_style.get_mut(input.selection_node),
+        ) {
+            let start = layout.x(selection.start);
+            let end = layout.x(selection.end);
+            if style.left != Val::Px(left + start)
+                || style.width != Val::Px(end - start)
+                || style.top != top
+            {
+                style.left = Val::Px(left + start);
+                style.width = Val::Px(end - start);
+                style.top = top;
+            }
+        }
+
+        // Candidate box of the IME below the caret
+        if focused.0 == Some(entity) {
+            if let Some(mut window) = input_window.get_mut(entity) {
+                let origin = node.logical_rect(transform).min;
+                window.ime_position =
+                    origin + Vec2::new(left + caret_x, layout.padding.y + layout.line_height);
+            }
+        }
+    }
+}
+
+pub(crate) fn update_text_edit_caret<C: TextEditWidget>(
+    q_widgets: Query<(Entity, &C)>,
+    mut q_visibility: Query<&mut Visibility>,
+    focused: Res<FocusedEntity>,
+    time: Res<Time<Real>>,
+) {
+    for (entity, widget) in &q_widgets {
+        let edit = widget.edit();
+        let has_focus = focused.0 == Some(entity);
+        let blink_phase = (time.elapsed_seconds() - widget.caret_moved_at()) / CARET_BLINK_INTERVAL;
+        let show_caret = has_focus && blink_phase % 2. < 1.;
+        let show_selection =
+            has_focus && edit.selection().is_some() && edit.composition().is_none();
+
+        let nodes = widget
+            .selection_nodes()
+            .iter()
+            .map(|node| (*node, show_selection))
+            .chain([(widget.caret_node(), show_caret)]);
+        for (node, visible) in nodes {
+            let Ok(mut visibility) = q_visibility.get_mut(node) else {
+                continue;
+            };
+
+            let target = match visible {
+                true => Visibility::Inherited,
+                false => Visibility::Hidden,
+            };
+            if *visibility != target {
+                *visibility = target;
+            }
+        }
+    }
+}
+
+pub trait UiTextInputExt<'w, 's> {
+    fn text_input<'a>(&'a mut self, config: TextInputConfig) -> UiBuilder<'w, 's, 'a, Entity>;
+}
+
+impl<'w, 's> UiTextInputExt<'w, 's> for UiBuilder<'w, 's, '_, Entity> {
+    fn text_input<'a>(&'a mut self, config: TextInputConfig) -> UiBuilder<'w, 's, 'a, Entity> {
+        let mut text_node = Entity::PLACEHOLDER;
+        let mut caret_node = Entity::PLACEHOLDER;
+        let mut selection_node = Entity::PLACEHOLDER;
+
+        let mut input = self.container(TextInput::frame(), |container| {
+            selection_node = container.spawn(TextInput::selection_bundle()).id();
+            text_node = container.spawn(TextInput::text_bundle()).id();
+            caret_node = container.spawn(TextInput::caret_bundle()).id();
+        });
+
+        let mut text_input = TextInput {
+            config,
+            text_node,
+            caret_node,
+            selection_node,
+            ..default()
+        };
+        text_input.set_value(text_input.config.value.clone());
+        input.insert(text_input);
+
+        input
+    }
+}
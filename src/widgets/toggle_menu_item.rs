@@ -1,6 +1,9 @@
 use bevy::prelude::*;
 
-use crate::ui_builder::UiBuilder;
+use crate::{
+    interactions::{set_pseudo_state, PseudoState, PseudoStates},
+    ui_builder::UiBuilder,
+};
 
 use super::prelude::{MenuItem, MenuItemConfig, MenuItemUpdate, UiMenuItemExt};
 
@@ -32,9 +35,14 @@ fn update_toggle_menu_item_value(
 }
 
 fn update_toggle_menu_checkmark(
-    mut q_menu_items: Query<(&ToggleMenuItem, &mut MenuItemConfig), Changed<ToggleMenuItem>>,
+    mut q_menu_items: Query<
+        (&ToggleMenuItem, &mut MenuItemConfig, &mut PseudoStates),
+        Changed<ToggleMenuItem>,
+    >,
 ) {
-    for (toggle, mut config) in &mut q_menu_items {
+    for (toggle, mut config, mut pseudo_states) in &mut q_menu_items {
+        set_pseudo_state(&mut pseudo_states, PseudoState::Checked, toggle.checked);
+
         if toggle.checked {
             config.leading_icon = "embedded://sickle_ui/icons/checkmark.png"
                 .to_string()
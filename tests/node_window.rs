@@ -0,0 +1,141 @@
+use bevy::{
+    ecs::system::CommandQueue,
+    input::InputPlugin,
+    prelude::*,
+    render::camera::RenderTarget,
+    ui::{TargetCamera, UiStack},
+    window::{CursorGrabMode, PrimaryWindow, WindowRef},
+};
+use sickle_ui::{
+    drag_interaction::{DragInteractionPlugin, DragState, Draggable},
+    ui_commands::SetCursorExt,
+    FluxInteractionPlugin, TrackedInteraction,
+};
+
+struct TwoWindows {
+    app: App,
+    windows: [Entity; 2],
+    node: Entity,
+}
+
+/// Two windows with one camera each, and a draggable node rendered into the second one.
+fn two_windows() -> TwoWindows {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        FluxInteractionPlugin,
+        DragInteractionPlugin,
+    ))
+    .init_resource::<UiScale>()
+    .init_resource::<UiStack>();
+
+    let primary = app.world.spawn((Window::default(), PrimaryWindow)).id();
+    let secondary = app.world.spawn(Window::default()).id();
+
+    let cameras = [primary, secondary].map(|window| {
+        app.world
+            .spawn(Camera {
+                target: RenderTarget::Window(WindowRef::Entity(window)),
+                ..default()
+            })
+            .id()
+    });
+
+    app.world
+        .spawn((NodeBundle::default(), TargetCamera(cameras[0])));
+    let node = app
+        .world
+        .spawn((
+            NodeBundle::default(),
+            TargetCamera(cameras[1]),
+            Interaction::default(),
+            TrackedInteraction::default(),
+            Draggable::default(),
+        ))
+        .id();
+
+    app.update();
+
+    TwoWindows {
+        app,
+        windows: [primary, secondary],
+        node,
+    }
+}
+
+fn window(app: &App, window: Entity) -> &Window {
+    app.world.get::<Window>(window).unwrap()
+}
+
+fn set_cursor_position(app: &mut App, window: Entity, position: Vec2) {
+    app.world
+        .get_mut::<Window>(window)
+        .unwrap()
+        .set_cursor_position(position.into());
+}
+
+#[test]
+fn set_cursor_targets_node_window() {
+    let TwoWindows {
+        mut app,
+        windows: [primary, secondary],
+        node,
+    } = two_windows();
+
+    let mut queue = CommandQueue::default();
+    let mut commands = Commands::new(&mut queue, &app.world);
+    commands.entity(node).set_cursor(CursorIcon::Grab);
+    queue.apply(&mut app.world);
+
+    assert_eq!(window(&app, secondary).cursor.icon, CursorIcon::Grab);
+    assert_eq!(window(&app, primary).cursor.icon, CursorIcon::Default);
+}
+
+#[test]
+fn drag_and_confinement_target_node_window() {
+    let TwoWindows {
+        mut app,
+        windows: [primary, secondary],
+        node,
+    } = two_windows();
+
+    set_cursor_position(&mut app, primary, Vec2::new(500., 500.));
+    set_cursor_position(&mut app, secondary, Vec2::new(10., 10.));
+    app.world
+        .resource_mut::<ButtonInput<MouseButton>>()
+        .press(MouseButton::Left);
+    *app.world.get_mut::<Interaction>(node).unwrap() = Interaction::Pressed;
+    app.update();
+
+    let draggable = *app.world.get::<Draggable>(node).unwrap();
+    assert_eq!(draggable.state, DragState::MaybeDragged);
+    assert_eq!(draggable.origin, Some(Vec2::new(10., 10.)));
+
+    // Only the secondary window's cursor drives the drag
+    set_cursor_position(&mut app, primary, Vec2::new(600., 550.));
+    set_cursor_position(&mut app, secondary, Vec2::new(15., 12.));
+    app.update();
+
+    let draggable = *app.world.get::<Draggable>(node).unwrap();
+    assert_eq!(draggable.state, DragState::DragStart);
+    assert_eq!(draggable.diff, Some(Vec2::new(5., 2.)));
+    assert_eq!(
+        window(&app, secondary).cursor.grab_mode,
+        CursorGrabMode::Confined
+    );
+    assert_eq!(window(&app, primary).cursor.grab_mode, CursorGrabMode::None);
+
+    *app.world.get_mut::<Interaction>(node).unwrap() = Interaction::Hovered;
+    app.update();
+
+    assert_eq!(
+        app.world.get::<Draggable>(node).unwrap().state,
+        DragState::DragEnd
+    );
+    assert_eq!(
+        window(&app, secondary).cursor.grab_mode,
+        CursorGrabMode::None
+    );
+    assert_eq!(window(&app, primary).cursor.grab_mode, CursorGrabMode::None);
+}